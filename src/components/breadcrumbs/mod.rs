@@ -0,0 +1,295 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::Range;
+
+use gloo::events::EventListener;
+use web_sys::Node;
+use web_sys::wasm_bindgen::JsCast;
+use yew::prelude::*;
+use yew_router::prelude::*;
+use yew_router::scope_ext::LocationHandle;
+
+/// How deep a trail can be, in case parents loop.
+const MAX_DEPTH: usize = 32;
+
+/// A route with a place in the [`Breadcrumbs`] trail.
+pub trait Breadcrumb: Routable {
+    /// The route one level up, `None` for the root of the trail.
+    fn parent(&self) -> Option<Self>;
+
+    fn label(&self) -> String;
+}
+
+/// Returns the routes from the root down to `route`.
+pub fn trail<R: Breadcrumb>(route: R) -> Vec<R> {
+    let mut trail = vec![route];
+    while let Some(parent) = trail.last().and_then(R::parent) {
+        if trail.len() == MAX_DEPTH {
+            break;
+        }
+        trail.push(parent);
+    }
+    trail.reverse();
+
+    trail
+}
+
+/// Returns the items of a trail of `len` items hidden behind an ellipsis, so that it shows at most
+/// `max_items` including the ellipsis. The first and the last items are always shown.
+pub fn hidden_range(len: usize, max_items: usize) -> Option<Range<usize>> {
+    let max_items = max_items.max(3);
+
+    (len > max_items).then(|| 1..len - (max_items - 2))
+}
+
+/// Properties for the [`Breadcrumbs`].
+#[derive(Debug, PartialEq, Properties)]
+pub struct BreadcrumbsProperties {
+    /// How many items are shown at most, including the ellipsis hiding the ones in the middle.
+    #[prop_or(4)]
+    pub max_items: usize,
+    /// The accessible name of the navigation.
+    #[prop_or(AttrValue::from("Breadcrumb"))]
+    pub label: AttrValue,
+    #[prop_or_default]
+    pub class: Classes,
+    #[prop_or_default]
+    pub style: Option<AttrValue>,
+}
+
+#[derive(Debug)]
+pub enum BreadcrumbsMessage {
+    LocationChanged,
+    ToggleMenu,
+    CloseMenu,
+    KeyDown(KeyboardEvent),
+}
+
+/// The trail of links from the root down to the current route, following [`Breadcrumb::parent`].
+///
+/// When it is longer than `max_items`, the items in the middle are collapsed into an ellipsis
+/// opening a menu of them.
+///
+/// Usage:
+/// ```ignore
+/// impl Breadcrumb for Route {
+///     fn parent(&self) -> Option<Self> {
+///         match self {
+///             Route::Home => None,
+///             _ => Some(Route::Home),
+///         }
+///     }
+///
+///     fn label(&self) -> String {
+///         // ...
+///     }
+/// }
+///
+/// <Breadcrumbs<Route>
+///     // Optional
+///     max_items=3
+/// />
+/// ```
+pub struct Breadcrumbs<R: Breadcrumb + 'static> {
+    menu_open: bool,
+    ellipsis_ref: NodeRef,
+    /// Closes the menu on a pointer down outside of the ellipsis while it is open.
+    _outside_listener: Option<EventListener>,
+    _location_handle: Option<LocationHandle>,
+    _marker: PhantomData<R>,
+}
+
+// The location handle isn't `Debug`.
+impl<R: Breadcrumb + 'static> fmt::Debug for Breadcrumbs<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Breadcrumbs")
+            .field("menu_open", &self.menu_open)
+            .field("ellipsis_ref", &self.ellipsis_ref)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R: Breadcrumb + 'static> Breadcrumbs<R> {
+    fn set_menu_open(&mut self, ctx: &Context<Self>, open: bool) -> bool {
+        if self.menu_open == open {
+            return false;
+        }
+
+        self.menu_open = open;
+        self._outside_listener = open.then(|| {
+            let ellipsis_ref = self.ellipsis_ref.clone();
+            let close = ctx.link().callback(|_| BreadcrumbsMessage::CloseMenu);
+            EventListener::new(&gloo::utils::document(), "pointerdown", move |e| {
+                let inside = e.target().is_some_and(|target| {
+                    ellipsis_ref
+                        .cast::<Node>()
+                        .is_some_and(|node| node.contains(target.dyn_ref::<Node>()))
+                });
+                if !inside {
+                    close.emit(());
+                }
+            })
+        });
+        true
+    }
+
+    fn view_link(route: &R) -> Html {
+        html! {
+            <Link<R> to={route.clone()} classes="breadcrumbs-link">{ route.label() }</Link<R>>
+        }
+    }
+
+    fn view_current(ctx: &Context<Self>, route: &R) -> Html {
+        let path = route.to_path();
+        let href = match ctx.link().navigator().as_ref().and_then(|n| n.basename()) {
+            Some(basename) => format!("{}{path}", basename.trim_end_matches('/')),
+            None => path,
+        };
+
+        html! {
+            <a
+                class="breadcrumbs-link"
+                {href}
+                aria-current="page"
+                onclick={Callback::from(|e: MouseEvent| e.prevent_default())}
+            >
+                { route.label() }
+            </a>
+        }
+    }
+
+    fn view_ellipsis(&self, ctx: &Context<Self>, hidden: &[R]) -> Html {
+        html! {
+            <li
+                ref={self.ellipsis_ref.clone()}
+                class="breadcrumbs-item breadcrumbs-ellipsis"
+                onkeydown={ctx.link().callback(BreadcrumbsMessage::KeyDown)}
+            >
+                <button
+                    type="button"
+                    class="breadcrumbs-ellipsis-button"
+                    aria-label="Show hidden breadcrumbs"
+                    aria-expanded={self.menu_open.to_string()}
+                    onclick={ctx.link().callback(|_| BreadcrumbsMessage::ToggleMenu)}
+                >
+                    { "…" }
+                </button>
+                if self.menu_open {
+                    <ul class="breadcrumbs-menu">
+                        { for hidden.iter().map(|route| {
+                            html! { <li>{ Self::view_link(route) }</li> }
+                        }) }
+                    </ul>
+                }
+            </li>
+        }
+    }
+}
+
+impl<R: Breadcrumb + 'static> Component for Breadcrumbs<R> {
+    type Message = BreadcrumbsMessage;
+    type Properties = BreadcrumbsProperties;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let location_handle = ctx
+            .link()
+            .add_location_listener(ctx.link().callback(|_| BreadcrumbsMessage::LocationChanged));
+
+        Self {
+            menu_open: false,
+            ellipsis_ref: NodeRef::default(),
+            _outside_listener: None,
+            _location_handle: location_handle,
+            _marker: PhantomData,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            BreadcrumbsMessage::LocationChanged => {
+                self.set_menu_open(ctx, false);
+                true
+            }
+            BreadcrumbsMessage::CloseMenu => self.set_menu_open(ctx, false),
+            BreadcrumbsMessage::ToggleMenu => self.set_menu_open(ctx, !self.menu_open),
+            BreadcrumbsMessage::KeyDown(e) => {
+                if e.key() != "Escape" || !self.menu_open {
+                    return false;
+                }
+
+                e.stop_propagation();
+                self.set_menu_open(ctx, false)
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let Self::Properties {
+            max_items,
+            label,
+            class,
+            style,
+        } = ctx.props();
+
+        let Some(route) = ctx.link().route::<R>() else {
+            return html! {};
+        };
+        let trail = trail(route);
+        let hidden = hidden_range(trail.len(), *max_items).unwrap_or_default();
+        let last = trail.len() - 1;
+
+        let items = trail.iter().enumerate().map(|(index, route)| {
+            if index == hidden.start && !hidden.is_empty() {
+                self.view_ellipsis(ctx, &trail[hidden.clone()])
+            } else if hidden.contains(&index) {
+                html! {}
+            } else if index == last {
+                html! { <li class="breadcrumbs-item">{ Self::view_current(ctx, route) }</li> }
+            } else {
+                html! { <li class="breadcrumbs-item">{ Self::view_link(route) }</li> }
+            }
+        });
+
+        html! {
+            <nav class={classes!("breadcrumbs", class.clone())} {style} aria-label={label.clone()}>
+                <ol class="breadcrumbs-list">{ for items }</ol>
+            </nav>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::route::Route;
+
+    #[test]
+    fn html_with_all_props() {
+        let _ = html! {
+            <Breadcrumbs<Route>
+                max_items=3
+                label="You are here"
+                class={classes!("test-class")}
+                style="padding: 1rem"
+            />
+        };
+    }
+
+    #[test]
+    fn trail_from_root() {
+        assert_eq!(trail(Route::Home), vec![Route::Home]);
+        assert_eq!(trail(Route::Dialog), vec![Route::Home, Route::Dialog]);
+    }
+
+    #[test]
+    fn collapsed_items() {
+        assert_eq!(hidden_range(4, 4), None);
+        assert_eq!(hidden_range(6, 4), Some(1..4));
+        assert_eq!(hidden_range(5, 3), Some(1..4));
+        assert_eq!(
+            hidden_range(5, 1),
+            Some(1..4),
+            "the first and last items are kept"
+        );
+    }
+}
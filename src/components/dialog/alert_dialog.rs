@@ -0,0 +1,130 @@
+use yew::prelude::*;
+
+use crate::components::{CloseReason, Dialog};
+
+/// Properties for the [`AlertDialog`].
+#[derive(Debug, PartialEq, Properties)]
+pub struct AlertDialogProperties {
+    pub children: Children,
+    pub open: bool,
+    #[prop_or_default]
+    pub class: Classes,
+    #[prop_or_default]
+    pub style: Option<AttrValue>,
+    pub on_close: Callback<CloseReason>,
+    /// The least destructive action, e.g. the cancel button, focused when the dialog opens.
+    pub least_destructive_ref: NodeRef,
+    /// Whether pressing Escape asks to close the dialog.
+    #[prop_or(true)]
+    pub close_on_escape: bool,
+    /// A guard called before `on_close`, returning `false` keeps the dialog open.
+    #[prop_or_default]
+    pub before_close: Option<Callback<CloseReason, bool>>,
+    /// Duration of the enter transition in milliseconds.
+    #[prop_or(200)]
+    pub enter_duration: u32,
+    /// Duration of the exit transition in milliseconds.
+    #[prop_or(200)]
+    pub exit_duration: u32,
+}
+
+/// A [`Dialog`] interrupting the user to confirm an action, e.g. a destructive one.
+///
+/// It has the `alertdialog` role, is not closed by clicking outside of it, and focuses the least
+/// destructive action when it opens, so that the action is not confirmed by accident.
+///
+/// Usage:
+/// ```ignore
+/// let cancel_ref = use_node_ref();
+///
+/// <AlertDialog open=true on_close={Callback::noop()} least_destructive_ref={cancel_ref.clone()}>
+///     <DialogHeader>
+///         <DialogTitle>{ "Delete the account?" }</DialogTitle>
+///     </DialogHeader>
+///     <DialogContent>
+///         { "This action cannot be undone." }
+///     </DialogContent>
+///     <DialogFooter>
+///         <button ref={cancel_ref}>{ "Cancel" }</button>
+///         <button>{ "Delete" }</button>
+///     </DialogFooter>
+/// </AlertDialog>
+/// ```
+#[derive(Debug)]
+pub struct AlertDialog;
+
+impl Component for AlertDialog {
+    type Message = ();
+    type Properties = AlertDialogProperties;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let Self::Properties {
+            children,
+            open,
+            class,
+            style,
+            on_close,
+            least_destructive_ref,
+            close_on_escape,
+            before_close,
+            enter_duration,
+            exit_duration,
+        } = ctx.props();
+
+        html! {
+            <Dialog
+                open={*open}
+                class={class.clone()}
+                style={style.clone()}
+                on_close={on_close.clone()}
+                role="alertdialog"
+                close_on_escape={*close_on_escape}
+                close_on_backdrop=false
+                before_close={before_close.clone()}
+                initial_focus={least_destructive_ref.clone()}
+                enter_duration={*enter_duration}
+                exit_duration={*exit_duration}
+            >
+                { children.clone() }
+            </Dialog>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{DialogContent, DialogFooter, DialogHeader, DialogTitle};
+
+    #[test]
+    fn html_with_all_props() {
+        let cancel_ref = NodeRef::default();
+
+        let _ = html! {
+            <AlertDialog
+                open=true
+                class={classes!("test-class")}
+                style="width: 20rem"
+                on_close={Callback::noop()}
+                least_destructive_ref={cancel_ref.clone()}
+                close_on_escape=false
+                before_close={Callback::from(|_| true)}
+                enter_duration=100
+                exit_duration=100
+            >
+                <DialogHeader>
+                    <DialogTitle>{ "Delete the account?" }</DialogTitle>
+                </DialogHeader>
+                <DialogContent>{ "This action cannot be undone." }</DialogContent>
+                <DialogFooter>
+                    <button ref={cancel_ref}>{ "Cancel" }</button>
+                    <button>{ "Delete" }</button>
+                </DialogFooter>
+            </AlertDialog>
+        };
+    }
+}
@@ -1,5 +1,33 @@
+use gloo::events::EventListener;
+use web_sys::Element;
 use yew::prelude::*;
 
+use crate::contexts::{DialogContext, DialogPart};
+
+/// Which edges of a scroll container have more content beyond them.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ScrollShadows {
+    pub top: bool,
+    pub bottom: bool,
+}
+
+impl ScrollShadows {
+    pub fn new(scroll_top: i32, scroll_height: i32, client_height: i32) -> Self {
+        Self {
+            top: scroll_top > 0,
+            bottom: scroll_height - client_height - scroll_top > 0,
+        }
+    }
+
+    pub fn of(element: &Element) -> Self {
+        Self::new(
+            element.scroll_top(),
+            element.scroll_height(),
+            element.client_height(),
+        )
+    }
+}
+
 /// The Dialog content has the following props:
 ///
 /// Required props:
@@ -20,14 +48,65 @@ pub struct DialogContentProperties {
 }
 
 #[derive(Debug)]
-pub struct DialogContent;
+pub enum DialogContentMessage {
+    /// The content was scrolled, rendered or resized, the shadows have to be updated.
+    Measure,
+}
+
+/// Describes the enclosing [`Dialog`](super::Dialog).
+///
+/// It is the only scrolling part of the dialog, so that the header and the footer stay visible,
+/// and gets the `scroll-shadow-top`/`scroll-shadow-bottom` classes when there is more to scroll.
+#[derive(Debug)]
+pub struct DialogContent {
+    id: Option<AttrValue>,
+    context: Option<DialogContext>,
+    node_ref: NodeRef,
+    shadows: ScrollShadows,
+    _resize_listener: EventListener,
+}
 
 impl Component for DialogContent {
-    type Message = ();
+    type Message = DialogContentMessage;
     type Properties = DialogContentProperties;
 
-    fn create(_ctx: &Context<Self>) -> Self {
-        Self
+    fn create(ctx: &Context<Self>) -> Self {
+        let context = ctx
+            .link()
+            .context::<DialogContext>(Callback::noop())
+            .map(|(context, _)| context);
+        let id = context
+            .as_ref()
+            .map(|context| context.mount(DialogPart::Description));
+        // Resizing the window resizes the content, e.g. of a dialog as high as the viewport.
+        let link = ctx.link().clone();
+        let resize_listener = EventListener::new(&gloo::utils::window(), "resize", move |_| {
+            link.send_message(DialogContentMessage::Measure)
+        });
+
+        Self {
+            id,
+            context,
+            node_ref: NodeRef::default(),
+            shadows: ScrollShadows::default(),
+            _resize_listener: resize_listener,
+        }
+    }
+
+    fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            DialogContentMessage::Measure => {
+                let Some(element) = self.node_ref.cast::<Element>() else {
+                    return false;
+                };
+
+                let shadows = ScrollShadows::of(&element);
+                let changed = shadows != self.shadows;
+                self.shadows = shadows;
+
+                changed
+            }
+        }
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
@@ -40,12 +119,59 @@ impl Component for DialogContent {
         html! {
             <div
                 class={classes!("dialog-content",
+                    self.shadows.top.then_some("scroll-shadow-top"),
+                    self.shadows.bottom.then_some("scroll-shadow-bottom"),
                     class.clone()
                 )}
+                id={self.id.clone()}
+                ref={self.node_ref.clone()}
+                onscroll={ctx.link().callback(|_| DialogContentMessage::Measure)}
                 {style}
             >
                 { children.clone() }
             </div>
         }
     }
+
+    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
+        // The children may have changed the scroll height.
+        ctx.link().send_message(DialogContentMessage::Measure);
+    }
+
+    fn destroy(&mut self, _ctx: &Context<Self>) {
+        if let Some(context) = &self.context {
+            context.unmount(DialogPart::Description);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scroll_shadows() {
+        assert_eq!(ScrollShadows::new(0, 100, 100), ScrollShadows::default());
+        assert_eq!(
+            ScrollShadows::new(0, 300, 100),
+            ScrollShadows {
+                top: false,
+                bottom: true
+            }
+        );
+        assert_eq!(
+            ScrollShadows::new(100, 300, 100),
+            ScrollShadows {
+                top: true,
+                bottom: true
+            }
+        );
+        assert_eq!(
+            ScrollShadows::new(200, 300, 100),
+            ScrollShadows {
+                top: true,
+                bottom: false
+            }
+        );
+    }
 }
@@ -1,5 +1,7 @@
 use yew::prelude::*;
 
+use crate::contexts::{DialogContext, DialogPart};
+
 /// The Dialog title has the following props:
 ///
 /// Required props:
@@ -19,15 +21,27 @@ pub struct DialogTitleProperties {
     pub style: Option<AttrValue>,
 }
 
+/// Labels the enclosing [`Dialog`](super::Dialog).
 #[derive(Debug)]
-pub struct DialogTitle;
+pub struct DialogTitle {
+    id: Option<AttrValue>,
+    context: Option<DialogContext>,
+}
 
 impl Component for DialogTitle {
     type Message = ();
     type Properties = DialogTitleProperties;
 
-    fn create(_ctx: &Context<Self>) -> Self {
-        Self
+    fn create(ctx: &Context<Self>) -> Self {
+        let context = ctx
+            .link()
+            .context::<DialogContext>(Callback::noop())
+            .map(|(context, _)| context);
+        let id = context
+            .as_ref()
+            .map(|context| context.mount(DialogPart::Title));
+
+        Self { id, context }
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
@@ -42,10 +56,17 @@ impl Component for DialogTitle {
                 class={classes!("dialog-title",
                     class.clone()
                 )}
+                id={self.id.clone()}
                 {style}
             >
                 { children.clone() }
             </div>
         }
     }
+
+    fn destroy(&mut self, _ctx: &Context<Self>) {
+        if let Some(context) = &self.context {
+            context.unmount(DialogPart::Title);
+        }
+    }
 }
@@ -0,0 +1,246 @@
+//! Position and size of a [`FloatingDialog`](super::FloatingDialog), in CSS pixels of the viewport.
+
+/// The edge or corner of a [`FloatingDialog`](super::FloatingDialog) a resize handle moves.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ResizeEdge {
+    Top,
+    TopRight,
+    Right,
+    BottomRight,
+    Bottom,
+    BottomLeft,
+    Left,
+    TopLeft,
+}
+
+impl ResizeEdge {
+    pub const ALL: [Self; 8] = [
+        Self::Top,
+        Self::TopRight,
+        Self::Right,
+        Self::BottomRight,
+        Self::Bottom,
+        Self::BottomLeft,
+        Self::Left,
+        Self::TopLeft,
+    ];
+
+    pub fn class(self) -> &'static str {
+        match self {
+            Self::Top => "resize-handle-n",
+            Self::TopRight => "resize-handle-ne",
+            Self::Right => "resize-handle-e",
+            Self::BottomRight => "resize-handle-se",
+            Self::Bottom => "resize-handle-s",
+            Self::BottomLeft => "resize-handle-sw",
+            Self::Left => "resize-handle-w",
+            Self::TopLeft => "resize-handle-nw",
+        }
+    }
+
+    /// Whether the edge moves the (left, right) sides.
+    fn horizontal(self) -> (bool, bool) {
+        match self {
+            Self::TopLeft | Self::Left | Self::BottomLeft => (true, false),
+            Self::TopRight | Self::Right | Self::BottomRight => (false, true),
+            Self::Top | Self::Bottom => (false, false),
+        }
+    }
+
+    /// Whether the edge moves the (top, bottom) sides.
+    fn vertical(self) -> (bool, bool) {
+        match self {
+            Self::TopLeft | Self::Top | Self::TopRight => (true, false),
+            Self::BottomLeft | Self::Bottom | Self::BottomRight => (false, true),
+            Self::Left | Self::Right => (false, false),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Size {
+    pub width: f64,
+    pub height: f64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Rect {
+    /// Returns a rect of the given size in the middle of the viewport.
+    pub fn centered(size: Size, viewport: Size) -> Self {
+        Self {
+            x: (viewport.width - size.width) / 2.0,
+            y: (viewport.height - size.height) / 2.0,
+            width: size.width,
+            height: size.height,
+        }
+        .clamped(viewport)
+    }
+
+    pub fn moved(self, dx: f64, dy: f64) -> Self {
+        Self {
+            x: self.x + dx,
+            y: self.y + dy,
+            ..self
+        }
+    }
+
+    /// Moves the sides of the edge by `(dx, dy)`, without getting smaller than `min` nor going
+    /// out of the viewport.
+    pub fn resized(self, edge: ResizeEdge, dx: f64, dy: f64, min: Size, viewport: Size) -> Self {
+        let (x, width) = resize_axis(
+            self.x,
+            self.width,
+            dx,
+            min.width,
+            viewport.width,
+            edge.horizontal(),
+        );
+        let (y, height) = resize_axis(
+            self.y,
+            self.height,
+            dy,
+            min.height,
+            viewport.height,
+            edge.vertical(),
+        );
+
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    /// Moves and shrinks the rect so that it fits in the viewport.
+    pub fn clamped(self, viewport: Size) -> Self {
+        let width = self.width.min(viewport.width);
+        let height = self.height.min(viewport.height);
+
+        Self {
+            x: self.x.clamp(0.0, viewport.width - width),
+            y: self.y.clamp(0.0, viewport.height - height),
+            width,
+            height,
+        }
+    }
+}
+
+/// Resizes a segment `[start, start + length]` of an axis by moving its (start, end) sides.
+fn resize_axis(
+    start: f64,
+    length: f64,
+    delta: f64,
+    min: f64,
+    viewport: f64,
+    (moves_start, moves_end): (bool, bool),
+) -> (f64, f64) {
+    let end = start + length;
+
+    if moves_start {
+        let start = (start + delta).clamp(0.0, (end - min).max(0.0));
+        (start, end - start)
+    } else if moves_end {
+        let end = (end + delta).clamp((start + min).min(viewport), viewport);
+        (start, end - start)
+    } else {
+        (start, length)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VIEWPORT: Size = Size {
+        width: 1000.0,
+        height: 800.0,
+    };
+    const MIN: Size = Size {
+        width: 200.0,
+        height: 100.0,
+    };
+
+    fn rect(x: f64, y: f64, width: f64, height: f64) -> Rect {
+        Rect {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn centered() {
+        let size = Size {
+            width: 400.0,
+            height: 300.0,
+        };
+
+        assert_eq!(
+            Rect::centered(size, VIEWPORT),
+            rect(300.0, 250.0, 400.0, 300.0)
+        );
+    }
+
+    #[test]
+    fn clamped_to_viewport() {
+        assert_eq!(
+            rect(-50.0, 700.0, 400.0, 300.0).clamped(VIEWPORT),
+            rect(0.0, 500.0, 400.0, 300.0)
+        );
+        assert_eq!(
+            rect(100.0, 100.0, 1200.0, 300.0).clamped(VIEWPORT),
+            rect(0.0, 100.0, 1000.0, 300.0)
+        );
+    }
+
+    #[test]
+    fn resized_from_edges() {
+        let start = rect(100.0, 100.0, 400.0, 300.0);
+
+        assert_eq!(
+            start.resized(ResizeEdge::Right, 50.0, 20.0, MIN, VIEWPORT),
+            rect(100.0, 100.0, 450.0, 300.0)
+        );
+        assert_eq!(
+            start.resized(ResizeEdge::TopLeft, -50.0, -20.0, MIN, VIEWPORT),
+            rect(50.0, 80.0, 450.0, 320.0)
+        );
+        assert_eq!(
+            start.resized(ResizeEdge::BottomLeft, 30.0, 10.0, MIN, VIEWPORT),
+            rect(130.0, 100.0, 370.0, 310.0)
+        );
+    }
+
+    #[test]
+    fn resized_within_bounds() {
+        let start = rect(100.0, 100.0, 400.0, 300.0);
+
+        // Not smaller than the minimum size.
+        assert_eq!(
+            start.resized(ResizeEdge::Left, 500.0, 0.0, MIN, VIEWPORT),
+            rect(300.0, 100.0, 200.0, 300.0)
+        );
+        assert_eq!(
+            start.resized(ResizeEdge::Bottom, 0.0, -500.0, MIN, VIEWPORT),
+            rect(100.0, 100.0, 400.0, 100.0)
+        );
+        // Not out of the viewport.
+        assert_eq!(
+            start.resized(ResizeEdge::TopLeft, -500.0, -500.0, MIN, VIEWPORT),
+            rect(0.0, 0.0, 500.0, 400.0)
+        );
+        assert_eq!(
+            start.resized(ResizeEdge::BottomRight, 1000.0, 1000.0, MIN, VIEWPORT),
+            rect(100.0, 100.0, 900.0, 700.0)
+        );
+    }
+}
@@ -0,0 +1,403 @@
+mod geometry;
+
+pub use geometry::{Rect, ResizeEdge, Size};
+
+use gloo::events::EventListener;
+use gloo::storage::{LocalStorage, Storage};
+use web_sys::{Element, HtmlDialogElement};
+use yew::prelude::*;
+
+use crate::components::CloseReason;
+use crate::contexts::{DialogContext, DialogPart, DialogParts, PortalContext};
+
+/// Controls dragging the dialog is ignored from, e.g. a close button in the header.
+const INTERACTIVE_SELECTOR: &str = "a, button, input, select, textarea, [contenteditable]";
+
+/// The `localStorage` key the last position and size of the dialog with the id are persisted
+/// under, as `[x, y, width, height]`.
+fn storage_key(id: &str) -> String {
+    format!("floating-dialog-{id}")
+}
+
+fn viewport() -> Size {
+    let root = gloo::utils::document_element();
+
+    Size {
+        width: root.client_width().into(),
+        height: root.client_height().into(),
+    }
+}
+
+/// Properties for the [`FloatingDialog`].
+#[derive(Debug, PartialEq, Properties)]
+pub struct FloatingDialogProperties {
+    pub children: Children,
+    pub open: bool,
+    pub on_close: Callback<CloseReason>,
+    /// Identifies the dialog to reopen it where it was last moved and resized to, also after a
+    /// reload.
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
+    #[prop_or_default]
+    pub class: Classes,
+    #[prop_or_default]
+    pub style: Option<AttrValue>,
+    /// Whether pressing Escape while the focus is inside of the dialog asks to close it.
+    #[prop_or(true)]
+    pub close_on_escape: bool,
+    /// The initial width in pixels.
+    #[prop_or(400)]
+    pub width: u32,
+    /// The initial height in pixels.
+    #[prop_or(300)]
+    pub height: u32,
+    #[prop_or(200)]
+    pub min_width: u32,
+    #[prop_or(120)]
+    pub min_height: u32,
+}
+
+impl FloatingDialogProperties {
+    fn min_size(&self) -> Size {
+        Size {
+            width: self.min_width.into(),
+            height: self.min_height.into(),
+        }
+    }
+
+    /// Returns where the dialog opens: where it was left last time, or in the middle of the viewport.
+    fn initial_rect(&self) -> Rect {
+        let viewport = viewport();
+        let remembered = self.id.as_ref().and_then(|id| {
+            let [x, y, width, height] = LocalStorage::get::<[f64; 4]>(storage_key(id)).ok()?;
+
+            Some(Rect {
+                x,
+                y,
+                width,
+                height,
+            })
+        });
+
+        match remembered {
+            Some(rect) => rect.clamped(viewport),
+            None => Rect::centered(
+                Size {
+                    width: self.width.into(),
+                    height: self.height.into(),
+                },
+                viewport,
+            ),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum FloatingDialogMessage {
+    /// A pointer was pressed on the dialog to drag it, or on a resize handle.
+    PointerDown(PointerEvent, Option<ResizeEdge>),
+    PointerMove(PointerEvent),
+    PointerUp,
+    KeyDown(KeyboardEvent),
+    ViewportResized,
+    /// A [`DialogTitle`](super::DialogTitle) or a [`DialogContent`](super::DialogContent) was
+    /// mounted or unmounted.
+    Part(DialogPart, bool),
+    /// The closest [`PortalProvider`](crate::contexts::PortalProvider) changed.
+    PortalChanged(PortalContext),
+}
+
+/// A dragging or resizing pointer.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Gesture {
+    pointer_id: i32,
+    /// `None` when dragging.
+    edge: Option<ResizeEdge>,
+    start_x: f64,
+    start_y: f64,
+    start_rect: Rect,
+}
+
+/// A non-modal [`Dialog`](super::Dialog), e.g. an inspector, which leaves the page interactive.
+///
+/// It is opened with `show()`, dragged by its [`DialogHeader`](super::DialogHeader) and resized
+/// from its edges, always staying within the viewport. Dialogs with an `id` reopen where they
+/// were left, their position and size being persisted to `localStorage`.
+///
+/// Usage:
+/// ```ignore
+/// <FloatingDialog
+///     open=true
+///     on_close={Callback::noop()}
+///     // Optional
+///     id="inspector"
+///     // Optional
+///     width=480
+///     height=360
+/// >
+///     <DialogHeader>
+///         <DialogTitle>{ "Inspector" }</DialogTitle>
+///     </DialogHeader>
+///     <DialogContent>
+///         { "..." }
+///     </DialogContent>
+/// </FloatingDialog>
+/// ```
+#[derive(Debug)]
+pub struct FloatingDialog {
+    context: DialogContext,
+    parts: DialogParts,
+    portal: PortalContext,
+    _portal_handle: Option<ContextHandle<PortalContext>>,
+    node_ref: NodeRef,
+    rect: Rect,
+    gesture: Option<Gesture>,
+    _resize_listener: EventListener,
+}
+
+impl FloatingDialog {
+    fn remember_rect(&self, ctx: &Context<Self>) {
+        if let Some(id) = &ctx.props().id {
+            let Rect {
+                x,
+                y,
+                width,
+                height,
+            } = self.rect;
+            let _ = LocalStorage::set(storage_key(id), [x, y, width, height]);
+        }
+    }
+}
+
+impl Component for FloatingDialog {
+    type Message = FloatingDialogMessage;
+    type Properties = FloatingDialogProperties;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let (portal, portal_handle) = match ctx
+            .link()
+            .context::<PortalContext>(ctx.link().callback(FloatingDialogMessage::PortalChanged))
+        {
+            Some((portal, handle)) => (portal, Some(handle)),
+            None => (PortalContext::default(), None),
+        };
+        let link = ctx.link().clone();
+        let resize_listener = EventListener::new(&gloo::utils::window(), "resize", move |_| {
+            link.send_message(FloatingDialogMessage::ViewportResized)
+        });
+
+        Self {
+            context: DialogContext::new(
+                ctx.link()
+                    .callback(|(part, mounted)| FloatingDialogMessage::Part(part, mounted)),
+            ),
+            parts: DialogParts::default(),
+            portal,
+            _portal_handle: portal_handle,
+            node_ref: NodeRef::default(),
+            rect: ctx.props().initial_rect(),
+            gesture: None,
+            _resize_listener: resize_listener,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let props = ctx.props();
+
+        match msg {
+            FloatingDialogMessage::PointerDown(e, edge) => {
+                let Some(target) = e.target_dyn_into::<Element>() else {
+                    return false;
+                };
+
+                if edge.is_none() {
+                    let is_header = target.closest(".dialog-header").ok().flatten().is_some();
+                    let is_interactive = target
+                        .closest(INTERACTIVE_SELECTOR)
+                        .ok()
+                        .flatten()
+                        .is_some();
+                    if !is_header || is_interactive {
+                        return false;
+                    }
+                }
+
+                e.prevent_default();
+                let _ = target.set_pointer_capture(e.pointer_id());
+                self.gesture = Some(Gesture {
+                    pointer_id: e.pointer_id(),
+                    edge,
+                    start_x: e.client_x().into(),
+                    start_y: e.client_y().into(),
+                    start_rect: self.rect,
+                });
+
+                true
+            }
+            FloatingDialogMessage::PointerMove(e) => {
+                let Some(gesture) = self.gesture.filter(|g| g.pointer_id == e.pointer_id()) else {
+                    return false;
+                };
+
+                let dx = f64::from(e.client_x()) - gesture.start_x;
+                let dy = f64::from(e.client_y()) - gesture.start_y;
+                let viewport = viewport();
+                self.rect = match gesture.edge {
+                    Some(edge) => {
+                        gesture
+                            .start_rect
+                            .resized(edge, dx, dy, props.min_size(), viewport)
+                    }
+                    None => gesture.start_rect.moved(dx, dy).clamped(viewport),
+                };
+
+                true
+            }
+            FloatingDialogMessage::PointerUp => {
+                if self.gesture.take().is_none() {
+                    return false;
+                }
+
+                self.remember_rect(ctx);
+                true
+            }
+            FloatingDialogMessage::KeyDown(e) => {
+                if props.open && props.close_on_escape && e.key() == "Escape" {
+                    e.stop_propagation();
+                    props.on_close.emit(CloseReason::Escape);
+                }
+
+                false
+            }
+            FloatingDialogMessage::ViewportResized => {
+                let rect = self.rect.clamped(viewport());
+                let changed = rect != self.rect;
+                self.rect = rect;
+
+                changed
+            }
+            FloatingDialogMessage::Part(part, mounted) => self.parts.update(part, mounted),
+            FloatingDialogMessage::PortalChanged(portal) => {
+                self.portal = portal;
+                true
+            }
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        if !old_props.open && ctx.props().open {
+            self.gesture = None;
+            self.rect = ctx.props().initial_rect();
+        }
+
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let Self::Properties {
+            children,
+            open,
+            class,
+            style,
+            ..
+        } = ctx.props();
+        if !open {
+            return html! {};
+        }
+
+        let Rect {
+            x,
+            y,
+            width,
+            height,
+        } = self.rect;
+        let style = format!(
+            "left: {x}px; top: {y}px; width: {width}px; height: {height}px; {}",
+            style.as_deref().unwrap_or_default()
+        );
+
+        let handles = ResizeEdge::ALL.into_iter().map(|edge| {
+            html! {
+                <div
+                    class={classes!("resize-handle", edge.class())}
+                    onpointerdown={ctx.link().callback(move |e| {
+                        FloatingDialogMessage::PointerDown(e, Some(edge))
+                    })}
+                />
+            }
+        });
+
+        let content = html! {
+            <dialog
+                class={classes!("floating-dialog",
+                    self.gesture.is_some().then_some("moving"),
+                    class.clone()
+                )}
+                {style}
+                aria-labelledby={self.parts.labelledby(&self.context)}
+                aria-describedby={self.parts.describedby(&self.context)}
+                ref={self.node_ref.clone()}
+                onpointermove={ctx.link().callback(FloatingDialogMessage::PointerMove)}
+                onpointerup={ctx.link().callback(|_| FloatingDialogMessage::PointerUp)}
+                onpointercancel={ctx.link().callback(|_| FloatingDialogMessage::PointerUp)}
+                onkeydown={ctx.link().callback(FloatingDialogMessage::KeyDown)}
+            >
+                <ContextProvider<DialogContext> context={self.context.clone()}>
+                    <div
+                        class="dialog"
+                        onpointerdown={ctx.link().callback(|e| {
+                            FloatingDialogMessage::PointerDown(e, None)
+                        })}
+                    >
+                        { children.clone() }
+                    </div>
+                </ContextProvider<DialogContext>>
+                { for handles }
+            </dialog>
+        };
+
+        match self.portal.container() {
+            Some(container) => create_portal(content, container),
+            None => content,
+        }
+    }
+
+    fn rendered(&mut self, _ctx: &Context<Self>, _first_render: bool) {
+        if let Some(dialog) = self
+            .node_ref
+            .cast::<HtmlDialogElement>()
+            .filter(|dialog| !dialog.open())
+        {
+            dialog.show();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::components::{DialogContent, DialogHeader, DialogTitle};
+
+    #[test]
+    fn html_with_all_props() {
+        let _ = html! {
+            <FloatingDialog
+                open=true
+                on_close={Callback::noop()}
+                id="inspector"
+                class={classes!("test-class")}
+                style="opacity: 0.9"
+                close_on_escape=false
+                width=480
+                height=360
+                min_width=240
+                min_height=160
+            >
+                <DialogHeader>
+                    <DialogTitle>{ "Inspector" }</DialogTitle>
+                </DialogHeader>
+                <DialogContent>{ "Content" }</DialogContent>
+            </FloatingDialog>
+        };
+    }
+}
@@ -0,0 +1,251 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use futures::future::LocalBoxFuture;
+use web_sys::{FormData, HtmlFormElement};
+use yew::prelude::*;
+
+use crate::components::{CloseReason, Dialog, DialogSize};
+
+/// The submission of a [`FormDialog`], failing with an error to show in the dialog.
+pub type SubmitFuture<E> = LocalBoxFuture<'static, Result<(), E>>;
+
+/// Properties for the [`FormDialog`].
+#[derive(Debug, PartialEq, Properties)]
+pub struct FormDialogProperties<E: fmt::Display + PartialEq + 'static = AttrValue> {
+    pub children: Children,
+    pub open: bool,
+    #[prop_or_default]
+    pub class: Classes,
+    #[prop_or_default]
+    pub style: Option<AttrValue>,
+    #[prop_or_default]
+    pub size: DialogSize,
+    pub on_close: Callback<CloseReason>,
+    /// Called with the data of the form when it is submitted, the dialog closes once it succeeds.
+    pub on_submit: Callback<FormData, SubmitFuture<E>>,
+    /// Whether pressing Escape asks to close the dialog.
+    #[prop_or(true)]
+    pub close_on_escape: bool,
+    /// Whether clicking outside of the dialog asks to close it.
+    #[prop_or(true)]
+    pub close_on_backdrop: bool,
+    /// A guard called before `on_close`, returning `false` keeps the dialog open.
+    /// It is not called when the form is submitted.
+    #[prop_or_default]
+    pub before_close: Option<Callback<CloseReason, bool>>,
+    /// The element to focus when the dialog opens.
+    #[prop_or_default]
+    pub initial_focus: NodeRef,
+    /// Duration of the enter transition in milliseconds.
+    #[prop_or(200)]
+    pub enter_duration: u32,
+    /// Duration of the exit transition in milliseconds.
+    #[prop_or(200)]
+    pub exit_duration: u32,
+}
+
+#[derive(Debug)]
+pub enum FormDialogMessage {
+    Submit(SubmitEvent),
+    /// The submission finished, with the error to show if it failed.
+    Submitted(Result<(), String>),
+}
+
+/// A [`Dialog`] whose content is a `<form method="dialog">`, closed once it is submitted successfully.
+///
+/// While `on_submit` runs, the controls of the form are disabled and the dialog can't be closed.
+/// If it fails, the dialog stays open and shows the error above the [`DialogFooter`](super::DialogFooter).
+///
+/// Usage:
+/// ```ignore
+/// <FormDialog<String>
+///     open=true
+///     on_close={Callback::noop()}
+///     on_submit={Callback::from(|data: FormData| {
+///         async move {
+///             match data.get("name").as_string() {
+///                 Some(name) if !name.is_empty() => save(name).await,
+///                 _ => Err("The name is required".to_string()),
+///             }
+///         }
+///         .boxed_local()
+///     })}
+/// >
+///     <DialogHeader>
+///         <DialogTitle>{ "Rename" }</DialogTitle>
+///     </DialogHeader>
+///     <DialogContent>
+///         <input name="name" />
+///     </DialogContent>
+///     <DialogFooter>
+///         <button type="submit">{ "Save" }</button>
+///     </DialogFooter>
+/// </FormDialog<String>>
+/// ```
+#[derive(Debug)]
+pub struct FormDialog<E: fmt::Display + PartialEq + 'static = AttrValue> {
+    pending: bool,
+    error: Option<String>,
+    _marker: PhantomData<E>,
+}
+
+impl<E: fmt::Display + PartialEq + 'static> Component for FormDialog<E> {
+    type Message = FormDialogMessage;
+    type Properties = FormDialogProperties<E>;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {
+            pending: false,
+            error: None,
+            _marker: PhantomData,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let FormDialogProperties {
+            open,
+            on_close,
+            on_submit,
+            ..
+        } = ctx.props();
+
+        match msg {
+            FormDialogMessage::Submit(e) => {
+                // Closed by us once the submission succeeds, rather than by the browser.
+                e.prevent_default();
+
+                let data = e
+                    .target_dyn_into::<HtmlFormElement>()
+                    .and_then(|form| FormData::new_with_form(&form).ok());
+                let Some(data) = data.filter(|_| !self.pending) else {
+                    return false;
+                };
+
+                self.pending = true;
+                self.error = None;
+
+                let submission = on_submit.emit(data);
+                ctx.link().send_future(async move {
+                    FormDialogMessage::Submitted(submission.await.map_err(|e| e.to_string()))
+                });
+            }
+            FormDialogMessage::Submitted(result) => {
+                self.pending = false;
+
+                match result {
+                    Ok(()) if *open => on_close.emit(CloseReason::Submit),
+                    Ok(()) => {}
+                    Err(error) => self.error = Some(error),
+                }
+            }
+        }
+
+        true
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        if !old_props.open && ctx.props().open {
+            self.error = None;
+        }
+
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let Self::Properties {
+            children,
+            open,
+            class,
+            style,
+            size,
+            on_close,
+            close_on_escape,
+            close_on_backdrop,
+            before_close,
+            initial_focus,
+            enter_duration,
+            exit_duration,
+            ..
+        } = ctx.props();
+
+        let before_close = {
+            let pending = self.pending;
+            let before_close = before_close.clone();
+            Callback::from(move |reason| {
+                !pending
+                    && before_close
+                        .as_ref()
+                        .is_none_or(|before_close| before_close.emit(reason))
+            })
+        };
+
+        html! {
+            <Dialog
+                open={*open}
+                class={class.clone()}
+                style={style.clone()}
+                size={*size}
+                on_close={on_close.clone()}
+                close_on_escape={*close_on_escape}
+                close_on_backdrop={*close_on_backdrop}
+                {before_close}
+                initial_focus={initial_focus.clone()}
+                enter_duration={*enter_duration}
+                exit_duration={*exit_duration}
+            >
+                <form
+                    method="dialog"
+                    class={classes!("dialog-form", self.pending.then_some("pending"))}
+                    aria-busy={self.pending.to_string()}
+                    onsubmit={ctx.link().callback(FormDialogMessage::Submit)}
+                >
+                    <fieldset class="dialog-form-fieldset" disabled={self.pending}>
+                        { children.clone() }
+                        if let Some(error) = &self.error {
+                            <div class="dialog-form-error" role="alert">{ error.clone() }</div>
+                        }
+                    </fieldset>
+                </form>
+            </Dialog>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::FutureExt;
+
+    use super::*;
+    use crate::components::{DialogContent, DialogFooter, DialogHeader, DialogTitle};
+
+    #[test]
+    fn html_with_all_props() {
+        let _ = html! {
+            <FormDialog<String>
+                open=true
+                class={classes!("test-class")}
+                style="width: 20rem"
+                size={DialogSize::Sm}
+                on_close={Callback::noop()}
+                on_submit={Callback::from(|_| async { Err("Invalid".to_string()) }.boxed_local())}
+                close_on_escape=false
+                close_on_backdrop=false
+                before_close={Callback::from(|_| true)}
+                initial_focus={NodeRef::default()}
+                enter_duration=100
+                exit_duration=100
+            >
+                <DialogHeader>
+                    <DialogTitle>{ "Rename" }</DialogTitle>
+                </DialogHeader>
+                <DialogContent>
+                    <input name="name" />
+                </DialogContent>
+                <DialogFooter>
+                    <button type="submit">{ "Save" }</button>
+                </DialogFooter>
+            </FormDialog<String>>
+        };
+    }
+}
@@ -1,16 +1,45 @@
+pub mod alert_dialog;
 pub mod dialog_content;
 pub mod dialog_footer;
 pub mod dialog_header;
 pub mod dialog_title;
+pub mod floating_dialog;
+pub mod form_dialog;
 
+pub use alert_dialog::AlertDialog;
 pub use dialog_content::DialogContent;
 pub use dialog_footer::DialogFooter;
 pub use dialog_header::DialogHeader;
 pub use dialog_title::DialogTitle;
+pub use floating_dialog::FloatingDialog;
+pub use form_dialog::FormDialog;
 
 use yew::prelude::*;
 
-use crate::components::Modal;
+use crate::components::{CloseReason, Modal};
+use crate::contexts::{DialogContext, DialogPart, DialogParts};
+
+/// The width of a [`Dialog`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DialogSize {
+    Sm,
+    #[default]
+    Md,
+    Lg,
+    /// Covers the whole viewport.
+    Fullscreen,
+}
+
+impl DialogSize {
+    pub fn class(self) -> &'static str {
+        match self {
+            Self::Sm => "dialog-sm",
+            Self::Md => "dialog-md",
+            Self::Lg => "dialog-lg",
+            Self::Fullscreen => "dialog-fullscreen",
+        }
+    }
+}
 
 /// Properties for the [`Dialog`].
 #[derive(Debug, PartialEq, Properties)]
@@ -21,7 +50,36 @@ pub struct DialogProperties {
     pub class: Classes,
     #[prop_or_default]
     pub style: Option<AttrValue>,
-    pub on_close: Callback<()>,
+    #[prop_or_default]
+    pub size: DialogSize,
+    pub on_close: Callback<CloseReason>,
+    /// Whether pressing Escape asks to close the dialog.
+    #[prop_or(true)]
+    pub close_on_escape: bool,
+    /// Whether clicking outside of the dialog asks to close it.
+    #[prop_or(true)]
+    pub close_on_backdrop: bool,
+    /// A guard called before `on_close`, returning `false` keeps the dialog open.
+    #[prop_or_default]
+    pub before_close: Option<Callback<CloseReason, bool>>,
+    /// The element to focus when the dialog opens.
+    #[prop_or_default]
+    pub initial_focus: NodeRef,
+    /// The ARIA role of the dialog, see [`AlertDialog`] for `alertdialog`.
+    #[prop_or(AttrValue::from("dialog"))]
+    pub role: AttrValue,
+    /// Duration of the enter transition in milliseconds.
+    #[prop_or(200)]
+    pub enter_duration: u32,
+    /// Duration of the exit transition in milliseconds.
+    #[prop_or(200)]
+    pub exit_duration: u32,
+}
+
+#[derive(Debug)]
+pub enum DialogMessage {
+    /// A [`DialogTitle`] or a [`DialogContent`] was mounted or unmounted.
+    Part(DialogPart, bool),
 }
 
 /// A container component to display content in a Dialog.
@@ -32,7 +90,16 @@ pub struct DialogProperties {
 ///
 /// Usage:
 /// ```ignore
-/// <Dialog open=true on_close={Callback::noop()}>
+/// <Dialog
+///     open=true
+///     on_close={Callback::noop()}
+///     // Optional
+///     size={DialogSize::Lg}
+///     // Optional, e.g. for forms with unsaved data
+///     close_on_backdrop=false
+///     // Optional
+///     before_close={Callback::from(|_| gloo::dialogs::confirm("Discard changes?"))}
+/// >
 ///     <DialogHeader>
 ///         <DialogTitle>{ "..." }</DialogTitle>
 ///     </DialogHeader>
@@ -45,15 +112,33 @@ pub struct DialogProperties {
 ///     </DialogFooter>
 /// </Dialog>
 /// ```
+///
+/// The dialog is labelled by its [`DialogTitle`] and described by its [`DialogContent`], when
+/// they are present. The content is the only part scrolling when it is too long.
 #[derive(Debug)]
-pub struct Dialog;
+pub struct Dialog {
+    context: DialogContext,
+    parts: DialogParts,
+}
 
 impl Component for Dialog {
-    type Message = ();
+    type Message = DialogMessage;
     type Properties = DialogProperties;
 
-    fn create(_ctx: &Context<Self>) -> Self {
-        Self
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            context: DialogContext::new(
+                ctx.link()
+                    .callback(|(part, mounted)| DialogMessage::Part(part, mounted)),
+            ),
+            parts: DialogParts::default(),
+        }
+    }
+
+    fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            DialogMessage::Part(part, mounted) => self.parts.update(part, mounted),
+        }
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
@@ -62,19 +147,42 @@ impl Component for Dialog {
             open,
             class,
             style,
+            size,
             on_close,
+            close_on_escape,
+            close_on_backdrop,
+            before_close,
+            initial_focus,
+            role,
+            enter_duration,
+            exit_duration,
         } = ctx.props();
-
         html! {
-            <Modal {open} {on_close}>
-                <div
-                    class={classes!("dialog",
-                        class.clone()
-                    )}
-                    {style}
-                >
-                    { children.clone() }
-                </div>
+            <Modal
+                {open}
+                {on_close}
+                class={classes!((*size == DialogSize::Fullscreen).then_some("modal-fullscreen"))}
+                close_on_escape={*close_on_escape}
+                close_on_backdrop={*close_on_backdrop}
+                before_close={before_close.clone()}
+                initial_focus={initial_focus.clone()}
+                role={role.clone()}
+                aria_labelledby={self.parts.labelledby(&self.context)}
+                aria_describedby={self.parts.describedby(&self.context)}
+                enter_duration={*enter_duration}
+                exit_duration={*exit_duration}
+            >
+                <ContextProvider<DialogContext> context={self.context.clone()}>
+                    <div
+                        class={classes!("dialog",
+                            size.class(),
+                            class.clone()
+                        )}
+                        {style}
+                    >
+                        { children.clone() }
+                    </div>
+                </ContextProvider<DialogContext>>
             </Modal>
         }
     }
@@ -84,6 +192,15 @@ impl Component for Dialog {
 mod test {
     use super::*;
 
+    #[test]
+    fn test_render_dialog_with_size() {
+        let _ = html! {
+            <Dialog open=true on_close={Callback::noop()} size={DialogSize::Fullscreen}>
+                <DialogContent>{ "Content" }</DialogContent>
+            </Dialog>
+        };
+    }
+
     #[test]
     fn test_render_dialog() {
         let _ = html! {
@@ -0,0 +1,275 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::fmt;
+use std::sync::Once;
+
+use gloo::events::EventListener;
+use web_sys::Element;
+use yew::prelude::*;
+use yew_router::prelude::*;
+use yew_router::scope_ext::LocationHandle;
+
+use crate::contexts::ErrorBoundaryContext;
+
+static INSTALL_PANIC_HOOK: Once = Once::new();
+
+thread_local! {
+    /// The mounted boundaries with their titles, the most recent last.
+    static BOUNDARIES: RefCell<Vec<(NodeRef, AttrValue)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Returns the message a panic was started with.
+pub fn panic_message(payload: &dyn Any) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Unknown error".to_string()
+    }
+}
+
+/// Creates an element of the fallback shown after a panic.
+fn panic_element(tag: &str, class: &str, text: &str) -> Option<Element> {
+    let element = gloo::utils::document().create_element(tag).ok()?;
+    element.set_class_name(class);
+    element.set_text_content(Some(text));
+
+    Some(element)
+}
+
+/// Shows the fallback of a panic over the page, outside of the elements rendered by Yew, which
+/// can't render anymore. Its button reloads the page.
+fn show_panic_fallback(title: &str, message: &str) -> Option<()> {
+    let body = gloo::utils::body();
+    if body.query_selector(".error-boundary-panic").ok()?.is_some() {
+        return Some(());
+    }
+
+    let fallback = panic_element("div", "error-boundary-fallback error-boundary-panic", "")?;
+    fallback.set_attribute("role", "alert").ok()?;
+    let retry = panic_element("button", "error-boundary-retry", "Reload")?;
+    retry.set_attribute("type", "button").ok()?;
+    EventListener::new(&retry, "click", |_| {
+        let _ = gloo::utils::window().location().reload();
+    })
+    .forget();
+
+    let title = panic_element("h1", "error-boundary-title", title)?;
+    let message = panic_element("p", "error-boundary-message", message)?;
+    fallback.append_with_node_3(&title, &message, &retry).ok()?;
+    body.append_child(&fallback).ok()?;
+
+    Some(())
+}
+
+/// Chains a panic hook showing the fallback with the title of the most recently mounted boundary.
+fn install_panic_hook() {
+    INSTALL_PANIC_HOOK.call_once(|| {
+        let previous = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            previous(info);
+            log::error!("{info}");
+
+            let title = BOUNDARIES
+                .try_with(|boundaries| {
+                    boundaries
+                        .try_borrow()
+                        .ok()
+                        .and_then(|b| b.last().map(|(_, title)| title.clone()))
+                })
+                .ok()
+                .flatten();
+            if let Some(title) = title {
+                show_panic_fallback(&title, &panic_message(info.payload()));
+            }
+        }));
+    });
+}
+
+/// Properties for the [`ErrorBoundary`].
+#[derive(Debug, PartialEq, Properties)]
+pub struct ErrorBoundaryProperties {
+    pub children: Children,
+    #[prop_or(AttrValue::from("Something went wrong"))]
+    pub title: AttrValue,
+    #[prop_or(AttrValue::from("Try again"))]
+    pub retry_label: AttrValue,
+    #[prop_or_default]
+    pub class: Classes,
+    #[prop_or_default]
+    pub style: Option<AttrValue>,
+}
+
+#[derive(Debug)]
+pub enum ErrorBoundaryMessage {
+    Report(String),
+    Retry,
+    LocationChanged,
+}
+
+/// Shows a fallback in place of its content when it fails.
+///
+/// The content reports errors with [`use_error_boundary`](crate::contexts::use_error_boundary),
+/// and is mounted again by the retry button of the fallback. Navigating away clears the error.
+///
+/// Panics can't be recovered from in WebAssembly, so after logging them, the boundary shows a
+/// fallback over the page, reloading it instead.
+///
+/// Usage:
+/// ```ignore
+/// <ErrorBoundary
+///     // Optional
+///     title="Oops"
+///     retry_label="Retry"
+/// >
+///     <Switch<Route> render={switch} />
+/// </ErrorBoundary>
+/// ```
+pub struct ErrorBoundary {
+    node_ref: NodeRef,
+    context: ErrorBoundaryContext,
+    error: Option<String>,
+    /// Changed to mount the content again.
+    generation: u32,
+    _location_handle: Option<LocationHandle>,
+}
+
+// The location handle isn't `Debug`.
+impl fmt::Debug for ErrorBoundary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ErrorBoundary")
+            .field("node_ref", &self.node_ref)
+            .field("context", &self.context)
+            .field("error", &self.error)
+            .field("generation", &self.generation)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Component for ErrorBoundary {
+    type Message = ErrorBoundaryMessage;
+    type Properties = ErrorBoundaryProperties;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        install_panic_hook();
+
+        let node_ref = NodeRef::default();
+        BOUNDARIES.with_borrow_mut(|boundaries| {
+            boundaries.push((node_ref.clone(), ctx.props().title.clone()));
+        });
+        let location_handle = ctx.link().add_location_listener(
+            ctx.link()
+                .callback(|_| ErrorBoundaryMessage::LocationChanged),
+        );
+
+        Self {
+            node_ref,
+            context: ErrorBoundaryContext {
+                report: ctx.link().callback(ErrorBoundaryMessage::Report),
+            },
+            error: None,
+            generation: 0,
+            _location_handle: location_handle,
+        }
+    }
+
+    fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            ErrorBoundaryMessage::Report(error) => {
+                log::error!("{error}");
+                self.error = Some(error);
+                true
+            }
+            ErrorBoundaryMessage::Retry => {
+                self.error = None;
+                self.generation = self.generation.wrapping_add(1);
+                true
+            }
+            ErrorBoundaryMessage::LocationChanged => self.error.take().is_some(),
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+        BOUNDARIES.with_borrow_mut(|boundaries| {
+            if let Some((_, title)) = boundaries.iter_mut().find(|(b, _)| *b == self.node_ref) {
+                title.clone_from(&ctx.props().title);
+            }
+        });
+
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let Self::Properties {
+            children,
+            title,
+            retry_label,
+            class,
+            style,
+        } = ctx.props();
+
+        let content = match &self.error {
+            Some(error) => html! {
+                <div class="error-boundary-fallback" role="alert">
+                    <h1 class="error-boundary-title">{ title.clone() }</h1>
+                    <p class="error-boundary-message">{ error.clone() }</p>
+                    <button
+                        type="button"
+                        class="error-boundary-retry"
+                        onclick={ctx.link().callback(|_| ErrorBoundaryMessage::Retry)}
+                    >
+                        { retry_label.clone() }
+                    </button>
+                </div>
+            },
+            None => html! {
+                <ContextProvider<ErrorBoundaryContext> context={self.context.clone()}>
+                    <div class="error-boundary-content" key={self.generation.to_string()}>
+                        { children.clone() }
+                    </div>
+                </ContextProvider<ErrorBoundaryContext>>
+            },
+        };
+
+        html! {
+            <div
+                class={classes!("error-boundary", class.clone())}
+                {style}
+                ref={self.node_ref.clone()}
+            >
+                { content }
+            </div>
+        }
+    }
+
+    fn destroy(&mut self, _ctx: &Context<Self>) {
+        BOUNDARIES.with_borrow_mut(|boundaries| boundaries.retain(|(b, _)| *b != self.node_ref));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn html_with_all_props() {
+        let _ = html! {
+            <ErrorBoundary
+                title="Oops"
+                retry_label="Retry"
+                class={classes!("test-class")}
+                style="padding: 1rem"
+            >
+                { "Content" }
+            </ErrorBoundary>
+        };
+    }
+
+    #[test]
+    fn message_of_panic() {
+        assert_eq!(panic_message(&"boom"), "boom");
+        assert_eq!(panic_message(&"boom".to_string()), "boom");
+        assert_eq!(panic_message(&42), "Unknown error");
+    }
+}
@@ -0,0 +1,109 @@
+use futures::future::LocalBoxFuture;
+use yew::prelude::*;
+use yew::suspense::Suspension;
+use yew_router::prelude::*;
+
+use crate::contexts::ErrorBoundaryContext;
+
+/// A route whose page is loaded asynchronously by the [`LazyPage`].
+///
+/// The page itself is compiled into the main wasm bundle, which Trunk can't split. What it loads
+/// is what the page needs besides its code, e.g. its stylesheet or data.
+pub trait LazyRoute: Routable {
+    /// Loads what the page of the route needs, or fails with the error to show instead. `None`
+    /// once it is loaded, so that the page renders without a fallback.
+    fn load(&self) -> Option<LocalBoxFuture<'static, Result<(), String>>>;
+
+    /// The page of the route, rendered once loaded.
+    fn page(&self) -> Html;
+
+    /// Shown while the page loads.
+    fn fallback(&self) -> Html {
+        html! {
+            <div class="lazy-page-loading" role="status" aria-live="polite">
+                <span class="lazy-page-spinner" aria-hidden="true" />
+                { "Loading…" }
+            </div>
+        }
+    }
+}
+
+/// Properties for the [`LazyPage`].
+#[derive(Debug, PartialEq, Properties)]
+pub struct LazyPageProperties<R: LazyRoute + 'static> {
+    pub route: R,
+}
+
+#[function_component(LazyPageContent)]
+fn lazy_page_content<R: LazyRoute + 'static>(props: &LazyPageProperties<R>) -> HtmlResult {
+    let error_boundary = use_context::<ErrorBoundaryContext>();
+    let failed_route = use_state(|| None::<R>);
+
+    if failed_route.as_ref() == Some(&props.route) {
+        return Ok(html! {});
+    }
+    let Some(loading) = props.route.load() else {
+        return Ok(props.route.page());
+    };
+
+    // Reported once the loading fails, rather than on each render.
+    let route = props.route.clone();
+    let suspension = Suspension::from_future(async move {
+        if let Err(error) = loading.await {
+            match error_boundary {
+                Some(error_boundary) => error_boundary.report.emit(error),
+                None => log::error!("{error}"),
+            }
+            failed_route.set(Some(route));
+        }
+    });
+
+    Err(suspension.into())
+}
+
+/// Renders the page of a route once [`LazyRoute::load`] has loaded it, and its
+/// [`LazyRoute::fallback`] meanwhile.
+///
+/// Loading errors are reported to the closest [`ErrorBoundary`](super::ErrorBoundary), whose
+/// retry button loads the page again. Pages already loaded render right away.
+///
+/// Usage:
+/// ```ignore
+/// impl LazyRoute for Route {
+///     fn load(&self) -> Option<LocalBoxFuture<'static, Result<(), String>>> {
+///         match self {
+///             Route::Charts => load_stylesheet("styles/charts.css"),
+///             _ => None,
+///         }
+///     }
+///
+///     fn page(&self) -> Html {
+///         // ...
+///     }
+/// }
+///
+/// fn switch(route: Route) -> Html {
+///     html! { <LazyPage<Route> {route} /> }
+/// }
+/// ```
+#[function_component(LazyPage)]
+pub fn lazy_page<R: LazyRoute + 'static>(props: &LazyPageProperties<R>) -> Html {
+    html! {
+        <Suspense fallback={props.route.fallback()}>
+            <LazyPageContent<R> route={props.route.clone()} />
+        </Suspense>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::route::Route;
+
+    #[test]
+    fn html_with_all_props() {
+        let _ = html! {
+            <LazyPage<Route> route={Route::Dialog} />
+        };
+    }
+}
@@ -1,13 +1,21 @@
+pub mod breadcrumbs;
 pub mod dialog;
+pub mod error_boundary;
+pub mod lazy_page;
 pub mod modal;
 pub mod pagination;
 pub mod segmented_control;
+pub mod sheet;
 pub mod sidebar;
 pub mod tabs;
 
+pub use breadcrumbs::*;
 pub use dialog::*;
+pub use error_boundary::*;
+pub use lazy_page::*;
 pub use modal::*;
 pub use pagination::*;
 pub use segmented_control::*;
+pub use sheet::*;
 pub use sidebar::*;
 pub use tabs::*;
@@ -0,0 +1,49 @@
+//! Keeps Tab/Shift+Tab navigation inside of a container.
+
+use web_sys::wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement};
+use yew::prelude::*;
+
+const FOCUSABLE_SELECTOR: &str = "a[href], area[href], button:not([disabled]), \
+    input:not([disabled]):not([type=\"hidden\"]), select:not([disabled]), \
+    textarea:not([disabled]), iframe, [contenteditable], [tabindex]:not([tabindex=\"-1\"])";
+
+/// Returns the focusable descendants of the container, in DOM order.
+pub fn focusable_elements(container: &Element) -> Vec<HtmlElement> {
+    let Ok(nodes) = container.query_selector_all(FOCUSABLE_SELECTOR) else {
+        return Vec::new();
+    };
+
+    (0..nodes.length())
+        .filter_map(|index| nodes.item(index))
+        .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+        .collect()
+}
+
+/// Wraps the focus around when Tab is pressed on the last focusable element of the container,
+/// or Shift+Tab on the first one.
+pub fn trap_focus(container: &Element, e: &KeyboardEvent) {
+    if e.key() != "Tab" {
+        return;
+    }
+
+    let focusable = focusable_elements(container);
+    let (Some(first), Some(last)) = (focusable.first(), focusable.last()) else {
+        e.prevent_default();
+        return;
+    };
+
+    let active = gloo::utils::document()
+        .active_element()
+        .and_then(|element| element.dyn_into::<HtmlElement>().ok());
+    let is_active = |element: &HtmlElement| active.as_ref() == Some(element);
+    let is_outside = !focusable.iter().any(is_active);
+
+    if e.shift_key() && (is_active(first) || is_outside) {
+        e.prevent_default();
+        let _ = last.focus();
+    } else if !e.shift_key() && (is_active(last) || is_outside) {
+        e.prevent_default();
+        let _ = first.focus();
+    }
+}
@@ -1,17 +1,110 @@
 //! Container based on [`<dialog>`](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/dialog) tag.
 
-use web_sys::{Element, HtmlElement};
+mod focus_trap;
+mod modal_stack;
+mod scroll_lock;
+mod transition;
+
+pub use modal_stack::any_modal_open;
+pub use transition::TransitionState;
+
+use gloo::render::{AnimationFrame, request_animation_frame};
+use gloo::timers::callback::Timeout;
+use web_sys::{HtmlDialogElement, HtmlElement};
 use yew::prelude::*;
 
-const APP_ID: &str = "app-root";
-const MODAL_ROOT_ID: &str = "modal-root";
+use focus_trap::trap_focus;
+use modal_stack::{close_modal, focused_element, is_top_modal, next_modal_id, open_modal};
+use transition::prefers_reduced_motion;
+
+use crate::contexts::PortalContext;
+
+/// Why a [`Modal`] asks to be closed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CloseReason {
+    /// Escape was pressed.
+    Escape,
+    /// The backdrop around the modal was clicked.
+    Backdrop,
+    /// The form of a [`FormDialog`](crate::components::FormDialog) was submitted successfully.
+    Submit,
+    /// The modal was swiped away, see [`Sheet`](crate::components::Sheet).
+    Swipe,
+    /// The `<dialog>` was closed by other means, e.g. a `<form method="dialog">` was submitted.
+    Programmatic,
+}
+
+/// Emits `on_close` unless vetoed by `before_close`, returns whether it was emitted.
+pub fn request_close(
+    before_close: Option<&Callback<CloseReason, bool>>,
+    on_close: &Callback<CloseReason>,
+    reason: CloseReason,
+) -> bool {
+    let allowed = before_close.is_none_or(|before_close| before_close.emit(reason));
+    if allowed {
+        on_close.emit(reason);
+    }
+
+    allowed
+}
 
 /// Properties for the [`Modal`].
 #[derive(Debug, PartialEq, Properties)]
 pub struct ModalProperties {
     pub children: Children,
     pub open: bool,
-    pub on_close: Callback<()>,
+    pub on_close: Callback<CloseReason>,
+    /// Whether pressing Escape asks to close the modal.
+    #[prop_or(true)]
+    pub close_on_escape: bool,
+    /// Whether clicking the backdrop asks to close the modal.
+    #[prop_or(true)]
+    pub close_on_backdrop: bool,
+    /// A guard called before `on_close`, returning `false` keeps the modal open.
+    #[prop_or_default]
+    pub before_close: Option<Callback<CloseReason, bool>>,
+    /// The element to focus when the modal opens, the `<dialog>` itself is focused if not provided.
+    #[prop_or_default]
+    pub initial_focus: NodeRef,
+    #[prop_or_default]
+    pub class: Classes,
+    /// The ARIA role of the `<dialog>`, e.g. `alertdialog`, its implicit role is `dialog`.
+    #[prop_or_default]
+    pub role: Option<AttrValue>,
+    /// The id of the element labelling the modal, e.g. its title.
+    #[prop_or_default]
+    pub aria_labelledby: Option<AttrValue>,
+    /// The id of the element describing the modal, e.g. its content.
+    #[prop_or_default]
+    pub aria_describedby: Option<AttrValue>,
+    /// Duration of the enter transition in milliseconds.
+    #[prop_or(200)]
+    pub enter_duration: u32,
+    /// Duration of the exit transition in milliseconds, the modal is unmounted after it at the latest.
+    #[prop_or(200)]
+    pub exit_duration: u32,
+    /// Class of the `<dialog>` when mounted, removed on the next frame to start the enter transition.
+    #[prop_or(AttrValue::from("entering"))]
+    pub entering_class: AttrValue,
+    /// Class of the `<dialog>` while the exit transition runs.
+    #[prop_or(AttrValue::from("exiting"))]
+    pub exiting_class: AttrValue,
+}
+
+#[derive(Debug)]
+pub enum ModalMessage {
+    /// The native `cancel` event, i.e. Escape was pressed.
+    Cancel,
+    /// The native `close` event.
+    Closed,
+    BackdropClick(MouseEvent),
+    KeyDown(KeyboardEvent),
+    Entered,
+    Exited,
+    /// A transition or an animation ended on the given target.
+    TransitionEnd(Option<HtmlElement>),
+    /// The closest [`PortalProvider`](crate::contexts::PortalProvider) changed.
+    PortalChanged(PortalContext),
 }
 
 /// A container based on [`<dialog>`](https://developer.mozilla.org/en-US/docs/Web/HTML/Reference/Elements/dialog) tag.
@@ -22,93 +115,331 @@ pub struct ModalProperties {
 ///
 /// <Modal
 ///     open=true
-///     on_close={Callback::noop()}
+///     on_close={Callback::from(|reason: CloseReason| log::info!("Closed by {reason:?}"))}
+///     // Optional
+///     close_on_escape=true
+///     // Optional
+///     close_on_backdrop=false
+///     // Optional
+///     before_close={Callback::from(|_| gloo::dialogs::confirm("Discard changes?"))}
+///     // Optional
+///     initial_focus={confirm_button_ref}
+///     // Optional
+///     exit_duration=300
 /// >
 ///     <h1>{ "This is a modal" }</h1>
 ///     <button onclick={Callback::noop()}>{"Close modal"}</button>
 /// </Modal>
 /// ```
 ///
+/// The `<dialog>` is opened with `showModal()`, so it is rendered in the top layer with a `::backdrop`,
+/// and Tab/Shift+Tab keep the focus inside of it.
+///
+/// Modals can be stacked, e.g. a confirmation opened from within a dialog: only the top-most
+/// one is interactive and handles Escape and backdrop clicks, and focus goes back to the
+/// previously focused element when each of them closes. The page behind is locked from scrolling
+/// while any modal is open.
+///
+/// Opening mounts the `<dialog>` with the `entering_class` and removes it on the next frame, closing
+/// keeps it mounted with the `exiting_class` until its transition ends or `exit_duration` elapses.
+/// Both are skipped when the user prefers reduced motion.
+///
+/// The `<dialog>` is rendered into the container of the closest [`PortalProvider`](crate::contexts::PortalProvider),
+/// or `#modal-root` (created if absent) when there is none.
+///
 /// Note:
 /// 1. focus outline of dialog is manually removed by us.
-/// 2. `<dialog>` caused incorrect vertical alignment on initial render, so we used `modal-backdrop` to do the alignment.
+/// 2. the `open` prop is the source of truth, the native `cancel` event is prevented and reported through `on_close`.
 #[derive(Debug)]
 pub struct Modal {
-    modal_root: Element,
+    id: usize,
+    portal: PortalContext,
+    _portal_handle: Option<ContextHandle<PortalContext>>,
     node_ref: NodeRef,
+    content_ref: NodeRef,
+    is_registered: bool,
+    state: TransitionState,
+    enter_frame: Option<AnimationFrame>,
+    exit_timeout: Option<Timeout>,
+}
+
+impl Modal {
+    fn dialog(&self) -> Option<HtmlDialogElement> {
+        self.node_ref.cast::<HtmlDialogElement>()
+    }
+
+    fn finish_exit(&mut self) {
+        self.state = TransitionState::Exited;
+        self.exit_timeout = None;
+
+        if let Some(dialog) = self.dialog().filter(|dialog| dialog.open()) {
+            dialog.close();
+        }
+    }
+
+    fn request_close(ctx: &Context<Self>, reason: CloseReason) -> bool {
+        let ModalProperties {
+            on_close,
+            before_close,
+            ..
+        } = ctx.props();
+
+        request_close(before_close.as_ref(), on_close, reason)
+    }
 }
 
 impl Component for Modal {
-    type Message = ();
+    type Message = ModalMessage;
     type Properties = ModalProperties;
 
-    fn create(_ctx: &Context<Self>) -> Self {
-        let modal_root = gloo::utils::document()
-            .get_element_by_id(MODAL_ROOT_ID)
-            .unwrap_or_else(|| panic!("Expected to find a #{MODAL_ROOT_ID} element"));
-        let node_ref = NodeRef::default();
+    fn create(ctx: &Context<Self>) -> Self {
+        let (portal, portal_handle) = match ctx
+            .link()
+            .context::<PortalContext>(ctx.link().callback(ModalMessage::PortalChanged))
+        {
+            Some((portal, handle)) => (portal, Some(handle)),
+            None => (PortalContext::default(), None),
+        };
+        let state = if ctx.props().open {
+            TransitionState::Entering
+        } else {
+            TransitionState::Exited
+        };
 
         Self {
-            modal_root,
-            node_ref,
+            id: next_modal_id(),
+            portal,
+            _portal_handle: portal_handle,
+            node_ref: NodeRef::default(),
+            content_ref: NodeRef::default(),
+            is_registered: false,
+            state,
+            enter_frame: None,
+            exit_timeout: None,
         }
     }
 
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let ModalProperties {
+            open,
+            close_on_escape,
+            close_on_backdrop,
+            ..
+        } = ctx.props();
+
+        match msg {
+            ModalMessage::Cancel => {
+                if *open && *close_on_escape && is_top_modal(self.id) {
+                    Self::request_close(ctx, CloseReason::Escape);
+                }
+            }
+            ModalMessage::Closed => {
+                // Closed by the browser while we still consider it open, e.g. `<form method="dialog">`.
+                if *open
+                    && !Self::request_close(ctx, CloseReason::Programmatic)
+                    && let Some(dialog) = self.dialog().filter(|dialog| !dialog.open())
+                {
+                    let _ = dialog.show_modal();
+                }
+            }
+            ModalMessage::BackdropClick(e) => {
+                let is_backdrop = self.dialog().is_some_and(|dialog| {
+                    e.target_dyn_into::<HtmlDialogElement>().as_ref() == Some(&dialog)
+                });
+
+                if *open && *close_on_backdrop && is_backdrop && is_top_modal(self.id) {
+                    Self::request_close(ctx, CloseReason::Backdrop);
+                }
+            }
+            ModalMessage::KeyDown(e) => {
+                if let Some(dialog) = self.dialog().filter(|_| is_top_modal(self.id)) {
+                    trap_focus(&dialog, &e);
+                }
+            }
+            ModalMessage::Entered => {
+                self.enter_frame = None;
+                if self.state == TransitionState::Entering {
+                    self.state = TransitionState::Entered;
+                    return true;
+                }
+            }
+            ModalMessage::Exited => {
+                if self.state == TransitionState::Exiting {
+                    self.finish_exit();
+                    return true;
+                }
+            }
+            ModalMessage::TransitionEnd(target) => {
+                // Ignore the transitions of the children, e.g. the indicator of `Tabs`.
+                let is_own = target.is_some_and(|target| {
+                    Some(&target) == self.node_ref.cast::<HtmlElement>().as_ref()
+                        || Some(&target) == self.content_ref.cast::<HtmlElement>().as_ref()
+                });
+
+                if is_own && self.state == TransitionState::Exiting {
+                    self.finish_exit();
+                    return true;
+                }
+            }
+            ModalMessage::PortalChanged(portal) => {
+                // An open modal keeps its inert target until it closes, only its container moves.
+                self.portal = portal;
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        let ModalProperties {
+            open,
+            exit_duration,
+            ..
+        } = ctx.props();
+
+        if !old_props.open && *open {
+            self.exit_timeout = None;
+            self.state = TransitionState::Entering;
+        } else if old_props.open && !*open {
+            self.enter_frame = None;
+
+            if *exit_duration == 0
+                || self.state == TransitionState::Exited
+                || prefers_reduced_motion()
+            {
+                self.finish_exit();
+            } else {
+                self.state = TransitionState::Exiting;
+
+                let link = ctx.link().clone();
+                self.exit_timeout = Some(Timeout::new(*exit_duration, move || {
+                    link.send_message(ModalMessage::Exited)
+                }));
+            }
+        }
+
+        true
+    }
+
     fn view(&self, ctx: &Context<Self>) -> Html {
         let Self::Properties {
             children,
-            open,
-            on_close,
+            class,
+            role,
+            aria_labelledby,
+            aria_describedby,
+            enter_duration,
+            exit_duration,
+            entering_class,
+            exiting_class,
             ..
         } = ctx.props();
 
+        if self.state == TransitionState::Exited {
+            return html! {};
+        }
+
+        let duration = if self.state == TransitionState::Exiting {
+            exit_duration
+        } else {
+            enter_duration
+        };
+
         let content = html! {
             <dialog
-                class="modal"
-                open={*open}
+                class={classes!("modal",
+                    self.state.class(entering_class, exiting_class).map(|class| class.to_string()),
+                    class.clone()
+                )}
+                style={format!("--modal-transition-duration: {duration}ms")}
+                role={role.clone()}
+                aria-modal="true"
+                aria-labelledby={aria_labelledby.clone()}
+                aria-describedby={aria_describedby.clone()}
+                tabindex="-1"
                 ref={self.node_ref.clone()}
-                onkeydown={let on_close = on_close.clone();
-                    Callback::from(move |e: KeyboardEvent| {
-                        if e.key() == "Escape" {
-                            on_close.emit(());
-                        }
-                    })}
+                oncancel={ctx.link().callback(|e: Event| {
+                    e.prevent_default();
+                    ModalMessage::Cancel
+                })}
+                onclose={ctx.link().callback(|_| ModalMessage::Closed)}
+                onclick={ctx.link().callback(ModalMessage::BackdropClick)}
+                onkeydown={ctx.link().callback(ModalMessage::KeyDown)}
+                ontransitionend={ctx.link().callback(|e: TransitionEvent| {
+                    ModalMessage::TransitionEnd(e.target_dyn_into::<HtmlElement>())
+                })}
+                onanimationend={ctx.link().callback(|e: AnimationEvent| {
+                    ModalMessage::TransitionEnd(e.target_dyn_into::<HtmlElement>())
+                })}
             >
-                <div
-                    class={classes!("modal-backdrop")}
-                    onclick={let on_close = on_close.clone();
-                        Callback::from(move |_| on_close.emit(()))}
-                >
-                    <div
-                        class={classes!("modal-content")}
-                        onclick={Callback::from(|e: MouseEvent| e.stop_propagation())}
-                    >
-                        { children.clone() }
-                    </div>
+                <div class={classes!("modal-content")} ref={self.content_ref.clone()}>
+                    { children.clone() }
                 </div>
             </dialog>
         };
 
-        if !*open {
-            return html! {};
+        // Rendered in place if there is nowhere to portal to, e.g. no `<body>`.
+        match self.portal.container() {
+            Some(container) => create_portal(content, container),
+            None => content,
         }
+    }
+
+    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
+        let ModalProperties {
+            initial_focus,
+            enter_duration,
+            ..
+        } = ctx.props();
+
+        match self.state {
+            TransitionState::Entering => {
+                if !self.is_registered {
+                    let return_focus = focused_element();
+                    if let Some(dialog) = self.dialog().filter(|dialog| !dialog.open()) {
+                        let _ = dialog.show_modal();
+                        // Flush the styles of the entering class, so that removing it transitions.
+                        let _ = dialog.offset_height();
+                    }
+                    open_modal(
+                        self.id,
+                        &self.node_ref,
+                        self.portal.inert_target(),
+                        return_focus,
+                    );
+                    self.is_registered = true;
 
-        let app_root = gloo::utils::document()
-            .get_element_by_id(APP_ID)
-            .unwrap_or_else(|| panic!("Expected to find a #{APP_ID} element"));
-        let _ = app_root.set_attribute("inert", "");
+                    let focus_target = initial_focus
+                        .cast::<HtmlElement>()
+                        .or_else(|| self.node_ref.cast::<HtmlElement>());
+                    if let Some(element) = focus_target {
+                        let _ = element.focus();
+                    }
+                }
 
-        create_portal(content, self.modal_root.clone())
+                if self.enter_frame.is_none() {
+                    if *enter_duration == 0 || prefers_reduced_motion() {
+                        ctx.link().send_message(ModalMessage::Entered);
+                    } else {
+                        let link = ctx.link().clone();
+                        self.enter_frame = Some(request_animation_frame(move |_| {
+                            link.send_message(ModalMessage::Entered)
+                        }));
+                    }
+                }
+            }
+            TransitionState::Exited if self.is_registered => {
+                close_modal(self.id);
+                self.is_registered = false;
+            }
+            _ => {}
+        }
     }
 
-    fn rendered(&mut self, _ctx: &Context<Self>, _first_render: bool) {
-        if !self.modal_root.has_child_nodes() {
-            let app_root = gloo::utils::document()
-                .get_element_by_id(APP_ID)
-                .unwrap_or_else(|| panic!("Expected to find a #{APP_ID} element"));
-            let _ = app_root.remove_attribute("inert");
-        } else if let Some(dialog) = self.node_ref.cast::<HtmlElement>() {
-            let _ = dialog.focus();
+    fn destroy(&mut self, _ctx: &Context<Self>) {
+        if self.is_registered {
+            close_modal(self.id);
         }
     }
 }
@@ -121,4 +452,63 @@ mod tests {
     fn render_modal() {
         let _ = html! { <Modal open=true on_close={Callback::noop()}>{ "Content" }</Modal> };
     }
+
+    #[test]
+    fn render_modal_with_dismissal_policy() {
+        let _ = html! {
+            <Modal
+                open=true
+                on_close={Callback::from(|reason: CloseReason| log::info!("Closed by {reason:?}"))}
+                close_on_escape=false
+                close_on_backdrop=false
+                before_close={Callback::from(|reason| reason != CloseReason::Backdrop)}
+            >
+                { "Content" }
+            </Modal>
+        };
+    }
+
+    #[test]
+    fn render_modal_with_transition() {
+        let _ = html! {
+            <Modal
+                open=true
+                on_close={Callback::noop()}
+                class={classes!("test-class")}
+                enter_duration=150
+                exit_duration=300
+                entering_class="fade-in"
+                exiting_class="fade-out"
+            >
+                { "Content" }
+            </Modal>
+        };
+    }
+
+    #[test]
+    fn render_modal_with_aria() {
+        let _ = html! {
+            <Modal
+                open=true
+                on_close={Callback::noop()}
+                role="alertdialog"
+                aria_labelledby="title"
+                aria_describedby="description"
+            >
+                <h2 id="title">{ "Title" }</h2>
+                <p id="description">{ "Description" }</p>
+            </Modal>
+        };
+    }
+
+    #[test]
+    fn render_modal_with_initial_focus() {
+        let initial_focus = NodeRef::default();
+
+        let _ = html! {
+            <Modal open=true on_close={Callback::noop()} initial_focus={initial_focus.clone()}>
+                <button ref={initial_focus}>{ "Confirm" }</button>
+            </Modal>
+        };
+    }
 }
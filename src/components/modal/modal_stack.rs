@@ -0,0 +1,226 @@
+//! Book-keeping of the open [`Modal`](super::Modal)s, in the order they were opened.
+//!
+//! Only the top-most modal is interactive: the inert target of the modals (usually the app root)
+//! is made `inert` while any of them is open, and every modal below the top one is made `inert`
+//! as well. When a modal closes, focus goes back to the element that was focused right before it
+//! opened.
+//!
+//! The page is also locked from scrolling while any modal is open.
+
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use web_sys::wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement};
+use yew::prelude::*;
+
+use super::scroll_lock::ScrollLock;
+
+static NEXT_MODAL_ID: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    static MODAL_STACK: RefCell<ModalStack> = RefCell::default();
+}
+
+/// A modal registered in the [`ModalStack`].
+#[derive(Debug)]
+pub struct ModalEntry {
+    pub id: usize,
+    pub dialog_ref: NodeRef,
+    /// The element made `inert` while the modal is open.
+    pub inert_target: Option<Element>,
+    /// The element focused right before the modal opened.
+    pub return_focus: Option<HtmlElement>,
+}
+
+/// The open modals, from the bottom-most to the top-most.
+#[derive(Debug, Default)]
+pub struct ModalStack {
+    entries: Vec<ModalEntry>,
+    scroll_lock: Option<ScrollLock>,
+}
+
+impl ModalStack {
+    /// Pushes a modal on top of the stack, returns the entry that was on top before.
+    pub fn push(&mut self, entry: ModalEntry) -> Option<&ModalEntry> {
+        self.entries.retain(|e| e.id != entry.id);
+        self.entries.push(entry);
+
+        self.entries.iter().rev().nth(1)
+    }
+
+    /// Removes a modal from the stack wherever it is, returns the removed entry.
+    pub fn remove(&mut self, id: usize) -> Option<ModalEntry> {
+        let index = self.entries.iter().position(|e| e.id == id)?;
+
+        Some(self.entries.remove(index))
+    }
+
+    pub fn top(&self) -> Option<&ModalEntry> {
+        self.entries.last()
+    }
+
+    pub fn is_top(&self, id: usize) -> bool {
+        self.top().is_some_and(|e| e.id == id)
+    }
+
+    /// Whether any of the modals still needs the element to be `inert`.
+    pub fn is_inert_target(&self, element: &Element) -> bool {
+        self.entries
+            .iter()
+            .any(|e| e.inert_target.as_ref() == Some(element))
+    }
+}
+
+/// Returns a new unique id to register a modal with.
+pub fn next_modal_id() -> usize {
+    NEXT_MODAL_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Whether the modal is the top-most one, i.e. the one which should handle Escape and backdrop clicks.
+pub fn is_top_modal(id: usize) -> bool {
+    MODAL_STACK.with_borrow(|stack| stack.is_top(id))
+}
+
+/// Returns the focused element, to give focus back to once a modal opened now closes.
+///
+/// It must be read before the modal is shown, which moves focus into it.
+pub fn focused_element() -> Option<HtmlElement> {
+    gloo::utils::document()
+        .active_element()
+        .and_then(|element| element.dyn_into::<HtmlElement>().ok())
+}
+
+/// Whether any modal is open, making the page behind it inert.
+pub fn any_modal_open() -> bool {
+    MODAL_STACK.with_borrow(|stack| stack.top().is_some())
+}
+
+/// Registers an opened modal on top of the stack.
+pub fn open_modal(
+    id: usize,
+    dialog_ref: &NodeRef,
+    inert_target: Option<Element>,
+    return_focus: Option<HtmlElement>,
+) {
+    let previous_top = MODAL_STACK.with_borrow_mut(|stack| {
+        if stack.scroll_lock.is_none() {
+            stack.scroll_lock = ScrollLock::lock();
+        }
+
+        stack
+            .push(ModalEntry {
+                id,
+                dialog_ref: dialog_ref.clone(),
+                inert_target: inert_target.clone(),
+                return_focus,
+            })
+            .map(|entry| entry.dialog_ref.clone())
+    });
+
+    if let Some(previous_ref) = previous_top {
+        set_inert(previous_ref.cast::<Element>(), true);
+    }
+    set_inert(inert_target, true);
+}
+
+/// Unregisters a closed modal, making the modal below it interactive again and restoring focus.
+pub fn close_modal(id: usize) {
+    let closed = MODAL_STACK.with_borrow_mut(|stack| {
+        let was_top = stack.is_top(id);
+        let entry = stack.remove(id)?;
+        let new_top = stack.top().map(|entry| entry.dialog_ref.clone());
+        let inert_target = entry
+            .inert_target
+            .clone()
+            .filter(|element| !stack.is_inert_target(element));
+        let scroll_lock = stack.scroll_lock.take_if(|_| new_top.is_none());
+
+        Some((entry, was_top, new_top, inert_target, scroll_lock))
+    });
+
+    let Some((entry, was_top, new_top, released_inert_target, scroll_lock)) = closed else {
+        return;
+    };
+
+    if let Some(scroll_lock) = scroll_lock {
+        scroll_lock.unlock();
+    }
+
+    if let Some(new_top_ref) = new_top.filter(|_| was_top) {
+        set_inert(new_top_ref.cast::<Element>(), false);
+    }
+    set_inert(released_inert_target, false);
+
+    if let Some(element) = entry
+        .return_focus
+        .filter(|element| was_top && element.is_connected())
+    {
+        let _ = element.focus();
+    }
+}
+
+fn set_inert(element: Option<Element>, inert: bool) {
+    if let Some(element) = element {
+        let _ = if inert {
+            element.set_attribute("inert", "")
+        } else {
+            element.remove_attribute("inert")
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: usize) -> ModalEntry {
+        ModalEntry {
+            id,
+            dialog_ref: NodeRef::default(),
+            inert_target: None,
+            return_focus: None,
+        }
+    }
+
+    #[test]
+    fn push_returns_previous_top() {
+        let mut stack = ModalStack::default();
+
+        assert!(stack.push(entry(1)).is_none());
+        assert_eq!(stack.push(entry(2)).map(|e| e.id), Some(1));
+        assert!(stack.is_top(2));
+        assert!(!stack.is_top(1));
+    }
+
+    #[test]
+    fn remove_keeps_order() {
+        let mut stack = ModalStack::default();
+        stack.push(entry(1));
+        stack.push(entry(2));
+        stack.push(entry(3));
+
+        assert_eq!(stack.remove(2).map(|e| e.id), Some(2));
+        assert!(stack.is_top(3));
+
+        assert_eq!(stack.remove(3).map(|e| e.id), Some(3));
+        assert!(stack.is_top(1));
+
+        assert!(stack.remove(3).is_none());
+        assert!(stack.remove(1).is_some());
+        assert!(stack.top().is_none());
+    }
+
+    #[test]
+    fn push_again_moves_to_top() {
+        let mut stack = ModalStack::default();
+        stack.push(entry(1));
+        stack.push(entry(2));
+        stack.push(entry(1));
+
+        assert!(stack.is_top(1));
+        assert_eq!(stack.remove(1).map(|e| e.id), Some(1));
+        assert!(stack.is_top(2));
+        assert!(stack.remove(1).is_none());
+    }
+}
@@ -0,0 +1,87 @@
+//! Prevents the page behind the open [`Modal`](super::Modal)s from scrolling.
+
+use web_sys::HtmlElement;
+
+/// The inline styles of `<body>` overridden while the page is locked, restored on unlock.
+#[derive(Debug)]
+pub struct ScrollLock {
+    body: HtmlElement,
+    overflow: String,
+    padding_right: String,
+}
+
+impl ScrollLock {
+    /// Hides the overflow of `<body>`, padding it by the width of the removed scrollbar so that the
+    /// page does not shift.
+    pub fn lock() -> Option<Self> {
+        let window = gloo::utils::window();
+        let body = gloo::utils::document().body()?;
+        let style = body.style();
+
+        let lock = Self {
+            overflow: style.get_property_value("overflow").ok()?,
+            padding_right: style.get_property_value("padding-right").ok()?,
+            body: body.clone(),
+        };
+
+        let window_width = window.inner_width().ok()?.as_f64()?;
+        let client_width = f64::from(gloo::utils::document_element().client_width());
+        let scrollbar_width = scrollbar_width(window_width, client_width);
+
+        if scrollbar_width > 0.0 {
+            let padding_right = window
+                .get_computed_style(&body)
+                .ok()
+                .flatten()
+                .and_then(|computed| computed.get_property_value("padding-right").ok())
+                .map_or(0.0, |padding| parse_px(&padding));
+            let _ = style.set_property(
+                "padding-right",
+                &format!("{}px", padding_right + scrollbar_width),
+            );
+        }
+        let _ = style.set_property("overflow", "hidden");
+
+        Some(lock)
+    }
+
+    pub fn unlock(self) {
+        let style = self.body.style();
+        let _ = style.set_property("overflow", &self.overflow);
+        let _ = style.set_property("padding-right", &self.padding_right);
+    }
+}
+
+/// Returns the width of the vertical scrollbar of the page, 0 for overlay scrollbars.
+fn scrollbar_width(window_width: f64, client_width: f64) -> f64 {
+    (window_width - client_width).max(0.0)
+}
+
+/// Parses a CSS length in pixels, e.g. a computed `padding-right`.
+fn parse_px(value: &str) -> f64 {
+    value
+        .trim()
+        .trim_end_matches("px")
+        .parse()
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scrollbar_width_is_never_negative() {
+        assert_eq!(scrollbar_width(1280.0, 1265.0), 15.0);
+        assert_eq!(scrollbar_width(1280.0, 1280.0), 0.0);
+        assert_eq!(scrollbar_width(1280.0, 1290.0), 0.0);
+    }
+
+    #[test]
+    fn parse_pixels() {
+        assert_eq!(parse_px("16px"), 16.0);
+        assert_eq!(parse_px(" 0.5px "), 0.5);
+        assert_eq!(parse_px(""), 0.0);
+        assert_eq!(parse_px("auto"), 0.0);
+    }
+}
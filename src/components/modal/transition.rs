@@ -0,0 +1,56 @@
+//! Enter/exit transitions of the [`Modal`](super::Modal).
+
+use yew::prelude::*;
+
+/// The phase of the transition of a [`Modal`](super::Modal).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TransitionState {
+    /// Mounted with the entering class, which is removed on the next frame to start the transition.
+    Entering,
+    Entered,
+    /// Still mounted with the exiting class, until the transition ends or the exit duration elapses.
+    Exiting,
+    /// Not mounted.
+    Exited,
+}
+
+impl TransitionState {
+    pub fn class(self, entering_class: &AttrValue, exiting_class: &AttrValue) -> Option<AttrValue> {
+        match self {
+            Self::Entering => Some(entering_class.clone()),
+            Self::Exiting => Some(exiting_class.clone()),
+            Self::Entered | Self::Exited => None,
+        }
+    }
+}
+
+/// Whether the user asked the system to minimize non-essential motion.
+pub fn prefers_reduced_motion() -> bool {
+    gloo::utils::window()
+        .match_media("(prefers-reduced-motion: reduce)")
+        .ok()
+        .flatten()
+        .is_some_and(|query| query.matches())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transition_class() {
+        let entering = AttrValue::from("entering");
+        let exiting = AttrValue::from("exiting");
+
+        assert_eq!(
+            TransitionState::Entering.class(&entering, &exiting),
+            Some(entering.clone())
+        );
+        assert_eq!(TransitionState::Entered.class(&entering, &exiting), None);
+        assert_eq!(
+            TransitionState::Exiting.class(&entering, &exiting),
+            Some(exiting.clone())
+        );
+        assert_eq!(TransitionState::Exited.class(&entering, &exiting), None);
+    }
+}
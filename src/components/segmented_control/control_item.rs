@@ -1,9 +1,11 @@
+use std::marker::PhantomData;
+
 use yew::prelude::*;
 
 /// Properties for the [`ControlItem`].
 #[derive(Clone, Debug, PartialEq, Properties)]
-pub struct ControlItemProperties {
-    pub value: AttrValue,
+pub struct ControlItemProperties<T: Clone + PartialEq + 'static = AttrValue> {
+    pub value: T,
     #[prop_or_default]
     pub children: Children,
     #[prop_or_default]
@@ -17,19 +19,23 @@ pub struct ControlItemProperties {
     #[prop_or_default]
     pub style: Option<AttrValue>,
     #[prop_or_default]
-    pub on_change: Callback<AttrValue>,
+    pub on_change: Callback<T>,
 }
 
 /// A component to represent a single item in a [`SegmentedControl`] component.
 #[derive(Debug)]
-pub struct ControlItem;
+pub struct ControlItem<T: Clone + PartialEq + 'static = AttrValue> {
+    _marker: PhantomData<T>,
+}
 
-impl Component for ControlItem {
+impl<T: Clone + PartialEq + 'static> Component for ControlItem<T> {
     type Message = ();
-    type Properties = ControlItemProperties;
+    type Properties = ControlItemProperties<T>;
 
     fn create(_ctx: &Context<Self>) -> Self {
-        Self
+        Self {
+            _marker: PhantomData,
+        }
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
@@ -69,6 +75,11 @@ impl Component for ControlItem {
 mod tests {
     use super::*;
 
+    #[derive(Clone, Debug, PartialEq)]
+    enum Flower {
+        Heather,
+    }
+
     #[test]
     fn html_with_all_props() {
         let _ = html! {
@@ -84,4 +95,13 @@ mod tests {
             </ControlItem>
         };
     }
+
+    #[test]
+    fn html_with_typed_value() {
+        let _ = html! {
+            <ControlItem<Flower> value={Flower::Heather} on_change={Callback::noop()}>
+                { "Heather" }
+            </ControlItem<Flower>>
+        };
+    }
 }
@@ -8,22 +8,23 @@ use yew::prelude::*;
 
 /// Properties for the [`SegmentedControl`].
 #[derive(Debug, PartialEq, Properties)]
-pub struct SegmentedControlProperties {
+pub struct SegmentedControlProperties<T: Clone + PartialEq + 'static = AttrValue> {
     #[prop_or_default]
-    pub children: ChildrenWithProps<ControlItem>,
+    pub children: ChildrenWithProps<ControlItem<T>>,
+    /// The value of the item selected initially, the first item is used if not provided.
     #[prop_or_default]
-    pub default_value: AttrValue,
+    pub default_value: Option<T>,
     #[prop_or_default]
     pub class: Classes,
     #[prop_or_default]
     pub style: Option<AttrValue>,
     #[prop_or_default]
-    pub on_change: Callback<AttrValue>,
+    pub on_change: Callback<T>,
 }
 
 #[derive(Debug)]
-pub enum SegmentedControlMessage {
-    Changed(AttrValue),
+pub enum SegmentedControlMessage<T> {
+    Changed(T),
 }
 
 /// A component to contain a list of [`ControlItem`].
@@ -45,42 +46,49 @@ pub enum SegmentedControlMessage {
 ///     </ControlItem>
 /// </SegmentedControl>
 /// ```
-/// A component to contain a list of [`ControlItem`].
+///
+/// The values default to [`AttrValue`], any other `Clone + PartialEq` type can be used instead:
+/// ```ignore
+/// <SegmentedControl<Flower>
+///     default_value={Flower::Heather}
+///     on_change={Callback::from(|flower: Flower| log::info!("Segmented Control changed to: {flower:?}"))}
+/// >
+///     <ControlItem<Flower> value={Flower::Dandelion}>{ "Dandelion" }</ControlItem<Flower>>
+///     <ControlItem<Flower> value={Flower::Heather}>{ "Heather" }</ControlItem<Flower>>
+/// </SegmentedControl<Flower>>
+/// ```
 #[derive(Debug)]
-pub struct SegmentedControl {
-    selected: AttrValue,
+pub struct SegmentedControl<T: Clone + PartialEq + 'static = AttrValue> {
+    selected: Option<T>,
 }
 
-impl SegmentedControl {
-    pub fn handle_change(&mut self, value: AttrValue, ctx: &Context<Self>) -> bool {
+impl<T: Clone + PartialEq + 'static> SegmentedControl<T> {
+    pub fn handle_change(&mut self, value: T, ctx: &Context<Self>) -> bool {
         let SegmentedControlProperties { on_change, .. } = ctx.props();
 
-        if value == self.selected {
+        if self.selected.as_ref() == Some(&value) {
             false
         } else {
-            self.selected = value;
-            on_change.emit(self.selected.clone());
+            self.selected = Some(value.clone());
+            on_change.emit(value);
 
             true
         }
     }
 }
 
-impl Component for SegmentedControl {
-    type Message = SegmentedControlMessage;
-    type Properties = SegmentedControlProperties;
+impl<T: Clone + PartialEq + 'static> Component for SegmentedControl<T> {
+    type Message = SegmentedControlMessage<T>;
+    type Properties = SegmentedControlProperties<T>;
 
     fn create(ctx: &Context<Self>) -> Self {
-        let selected = if ctx.props().default_value.is_empty() {
+        let selected = ctx.props().default_value.clone().or_else(|| {
             ctx.props()
                 .children
                 .iter()
                 .next()
                 .map(|child| child.props.value.clone())
-                .unwrap_or_default()
-        } else {
-            ctx.props().default_value.clone()
-        };
+        });
 
         Self { selected }
     }
@@ -105,7 +113,7 @@ impl Component for SegmentedControl {
                 let props = Rc::make_mut(&mut child.props);
                 let value = props.value.clone();
 
-                props.is_selected = value == self.selected;
+                props.is_selected = self.selected.as_ref() == Some(&value);
                 props.on_change = ctx.link().callback(Self::Message::Changed);
 
                 child
@@ -124,6 +132,12 @@ impl Component for SegmentedControl {
 mod tests {
     use super::*;
 
+    #[derive(Clone, Debug, PartialEq)]
+    enum Flower {
+        Dandelion,
+        Heather,
+    }
+
     #[test]
     fn html_with_all_props() {
         let _ = html! {
@@ -139,4 +153,17 @@ mod tests {
             </SegmentedControl>
         };
     }
+
+    #[test]
+    fn html_with_typed_values() {
+        let _ = html! {
+            <SegmentedControl<Flower>
+                default_value={Flower::Heather}
+                on_change={Callback::from(|flower: Flower| log::info!("Segmented Control changed to: {flower:?}"))}
+            >
+                <ControlItem<Flower> value={Flower::Dandelion}>{ "Dandelion" }</ControlItem<Flower>>
+                <ControlItem<Flower> value={Flower::Heather}>{ "Heather" }</ControlItem<Flower>>
+            </SegmentedControl<Flower>>
+        };
+    }
 }
@@ -0,0 +1,377 @@
+pub mod sheet_content;
+pub mod sheet_footer;
+pub mod sheet_header;
+pub mod sheet_title;
+
+pub use sheet_content::SheetContent;
+pub use sheet_footer::SheetFooter;
+pub use sheet_header::SheetHeader;
+pub use sheet_title::SheetTitle;
+
+use web_sys::Element;
+use yew::prelude::*;
+
+use crate::components::dialog::dialog_content::ScrollShadows;
+use crate::components::{CloseReason, Modal, request_close};
+use crate::contexts::{DialogContext, DialogPart, DialogParts};
+
+/// How far a [`Sheet`] has to be swiped towards its edge to be dismissed, in pixels.
+const SWIPE_DISMISS_THRESHOLD: f64 = 80.0;
+
+/// The edge of the screen a [`Sheet`] slides in from.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SheetSide {
+    Top,
+    #[default]
+    Right,
+    Bottom,
+    Left,
+}
+
+impl SheetSide {
+    pub fn class(self) -> &'static str {
+        match self {
+            Self::Top => "sheet-top",
+            Self::Right => "sheet-right",
+            Self::Bottom => "sheet-bottom",
+            Self::Left => "sheet-left",
+        }
+    }
+
+    /// Returns how far a swipe by `(dx, dy)` moved the sheet towards its edge, swipes away from
+    /// the edge are ignored.
+    pub fn swipe_offset(self, dx: f64, dy: f64) -> f64 {
+        let offset = match self {
+            Self::Top => -dy,
+            Self::Right => dx,
+            Self::Bottom => dy,
+            Self::Left => -dx,
+        };
+
+        offset.max(0.0)
+    }
+
+    /// Whether swiping towards the edge would scroll the content back rather than dismiss the
+    /// sheet, given the scroll position of the content.
+    pub fn swipe_scrolls(self, shadows: ScrollShadows) -> bool {
+        match self {
+            Self::Top => shadows.bottom,
+            Self::Bottom => shadows.top,
+            Self::Left | Self::Right => false,
+        }
+    }
+
+    /// Returns the CSS transform moving the sheet by `offset` pixels towards its edge.
+    pub fn transform(self, offset: f64) -> String {
+        match self {
+            Self::Top => format!("transform: translateY({}px)", -offset),
+            Self::Right => format!("transform: translateX({offset}px)"),
+            Self::Bottom => format!("transform: translateY({offset}px)"),
+            Self::Left => format!("transform: translateX({}px)", -offset),
+        }
+    }
+}
+
+/// Properties for the [`Sheet`].
+#[derive(Debug, PartialEq, Properties)]
+pub struct SheetProperties {
+    pub children: Children,
+    pub open: bool,
+    pub on_close: Callback<CloseReason>,
+    #[prop_or_default]
+    pub side: SheetSide,
+    /// The width of left and right sheets, or the height of top and bottom ones, as a CSS length.
+    #[prop_or(AttrValue::from("20rem"))]
+    pub size: AttrValue,
+    #[prop_or_default]
+    pub class: Classes,
+    #[prop_or_default]
+    pub style: Option<AttrValue>,
+    /// Whether pressing Escape asks to close the sheet.
+    #[prop_or(true)]
+    pub close_on_escape: bool,
+    /// Whether clicking outside of the sheet asks to close it.
+    #[prop_or(true)]
+    pub close_on_backdrop: bool,
+    /// Whether swiping the sheet towards its edge on touch screens asks to close it.
+    #[prop_or(true)]
+    pub swipe_to_dismiss: bool,
+    /// A guard called before `on_close`, returning `false` keeps the sheet open.
+    #[prop_or_default]
+    pub before_close: Option<Callback<CloseReason, bool>>,
+    /// The element to focus when the sheet opens.
+    #[prop_or_default]
+    pub initial_focus: NodeRef,
+    /// Duration of the enter transition in milliseconds.
+    #[prop_or(300)]
+    pub enter_duration: u32,
+    /// Duration of the exit transition in milliseconds.
+    #[prop_or(300)]
+    pub exit_duration: u32,
+}
+
+#[derive(Debug)]
+pub enum SheetMessage {
+    TouchStart(TouchEvent),
+    TouchMove(TouchEvent),
+    /// The touch ended or was cancelled.
+    Release,
+    /// A [`SheetTitle`] or a [`SheetContent`] was mounted or unmounted.
+    Part(DialogPart, bool),
+}
+
+/// A touch swiping the sheet.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Swipe {
+    start_x: f64,
+    start_y: f64,
+    offset: f64,
+}
+
+/// A panel sliding in from an edge of the screen, e.g. for detail views or filters on mobile.
+///
+/// It is a [`Modal`], so it is rendered in a portal, makes the rest of the page inert and is
+/// closed by Escape and backdrop clicks like a [`Dialog`](crate::components::Dialog).
+///
+/// Usage:
+/// ```ignore
+/// <Sheet
+///     open=true
+///     on_close={Callback::noop()}
+///     // Optional
+///     side={SheetSide::Bottom}
+///     // Optional
+///     size="50dvh"
+///     // Optional
+///     swipe_to_dismiss=false
+/// >
+///     <SheetHeader>
+///         <SheetTitle>{ "..." }</SheetTitle>
+///     </SheetHeader>
+///     <SheetContent>
+///         { "..." }
+///     </SheetContent>
+///     // Optional
+///     <SheetFooter>
+///         { "..." }
+///     </SheetFooter>
+/// </Sheet>
+/// ```
+///
+/// The sheet is labelled by its [`SheetTitle`] and described by its [`SheetContent`], when they
+/// are present.
+#[derive(Debug)]
+pub struct Sheet {
+    context: DialogContext,
+    parts: DialogParts,
+    swipe: Option<Swipe>,
+}
+
+impl Component for Sheet {
+    type Message = SheetMessage;
+    type Properties = SheetProperties;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            context: DialogContext::new(
+                ctx.link()
+                    .callback(|(part, mounted)| SheetMessage::Part(part, mounted)),
+            ),
+            parts: DialogParts::default(),
+            swipe: None,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let SheetProperties {
+            open,
+            on_close,
+            side,
+            swipe_to_dismiss,
+            before_close,
+            ..
+        } = ctx.props();
+
+        match msg {
+            SheetMessage::TouchStart(e) => {
+                let scrolls = e
+                    .target_dyn_into::<Element>()
+                    .and_then(|target| target.closest(".sheet-content").ok().flatten())
+                    .is_some_and(|content| side.swipe_scrolls(ScrollShadows::of(&content)));
+                let touches = e.touches();
+                self.swipe = touches
+                    .get(0)
+                    .filter(|_| *open && *swipe_to_dismiss && !scrolls && touches.length() == 1)
+                    .map(|touch| Swipe {
+                        start_x: touch.client_x().into(),
+                        start_y: touch.client_y().into(),
+                        offset: 0.0,
+                    });
+                false
+            }
+            SheetMessage::TouchMove(e) => {
+                let (Some(swipe), Some(touch)) = (self.swipe.as_mut(), e.touches().get(0)) else {
+                    return false;
+                };
+
+                swipe.offset = side.swipe_offset(
+                    f64::from(touch.client_x()) - swipe.start_x,
+                    f64::from(touch.client_y()) - swipe.start_y,
+                );
+                true
+            }
+            SheetMessage::Release => {
+                let Some(swipe) = self.swipe.take() else {
+                    return false;
+                };
+
+                // Released before the threshold, the sheet goes back in place.
+                if swipe.offset >= SWIPE_DISMISS_THRESHOLD {
+                    request_close(before_close.as_ref(), on_close, CloseReason::Swipe);
+                }
+                true
+            }
+            SheetMessage::Part(part, mounted) => self.parts.update(part, mounted),
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let Self::Properties {
+            children,
+            open,
+            on_close,
+            side,
+            size,
+            class,
+            style,
+            close_on_escape,
+            close_on_backdrop,
+            before_close,
+            initial_focus,
+            enter_duration,
+            exit_duration,
+            ..
+        } = ctx.props();
+        let swipe_style = self.swipe.map(|swipe| side.transform(swipe.offset));
+        let style = [
+            Some(format!("--sheet-size: {size}")),
+            swipe_style,
+            style.as_ref().map(|style| style.to_string()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join("; ");
+
+        html! {
+            <Modal
+                open={*open}
+                on_close={on_close.clone()}
+                class={classes!("sheet-modal", side.class())}
+                close_on_escape={*close_on_escape}
+                close_on_backdrop={*close_on_backdrop}
+                before_close={before_close.clone()}
+                initial_focus={initial_focus.clone()}
+                aria_labelledby={self.parts.labelledby(&self.context)}
+                aria_describedby={self.parts.describedby(&self.context)}
+                enter_duration={*enter_duration}
+                exit_duration={*exit_duration}
+            >
+                <ContextProvider<DialogContext> context={self.context.clone()}>
+                    <div
+                        class={classes!("sheet",
+                            self.swipe.is_some().then_some("swiping"),
+                            class.clone()
+                        )}
+                        {style}
+                        ontouchstart={ctx.link().callback(SheetMessage::TouchStart)}
+                        ontouchmove={ctx.link().callback(SheetMessage::TouchMove)}
+                        ontouchend={ctx.link().callback(|_| SheetMessage::Release)}
+                        ontouchcancel={ctx.link().callback(|_| SheetMessage::Release)}
+                    >
+                        { children.clone() }
+                    </div>
+                </ContextProvider<DialogContext>>
+            </Modal>
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn html_with_all_props() {
+        let _ = html! {
+            <Sheet
+                open=true
+                on_close={Callback::noop()}
+                side={SheetSide::Bottom}
+                size="50dvh"
+                class={classes!("test-class")}
+                style="padding: 1rem"
+                close_on_escape=false
+                close_on_backdrop=false
+                swipe_to_dismiss=false
+                before_close={Callback::from(|_| true)}
+                initial_focus={NodeRef::default()}
+                enter_duration=150
+                exit_duration=150
+            >
+                <SheetHeader>
+                    <SheetTitle>{ "Title" }</SheetTitle>
+                </SheetHeader>
+                <SheetContent>{ "Content" }</SheetContent>
+                <SheetFooter>{ "Footer" }</SheetFooter>
+            </Sheet>
+        };
+    }
+
+    #[test]
+    fn swipe_offset() {
+        assert_eq!(SheetSide::Right.swipe_offset(50.0, 10.0), 50.0);
+        assert_eq!(SheetSide::Right.swipe_offset(-50.0, 10.0), 0.0);
+        assert_eq!(SheetSide::Left.swipe_offset(-50.0, 10.0), 50.0);
+        assert_eq!(SheetSide::Bottom.swipe_offset(10.0, 50.0), 50.0);
+        assert_eq!(SheetSide::Top.swipe_offset(10.0, -50.0), 50.0);
+        assert_eq!(SheetSide::Top.swipe_offset(10.0, 50.0), 0.0);
+    }
+
+    #[test]
+    fn swipe_on_scrolled_content() {
+        let scrolled = ScrollShadows {
+            top: true,
+            bottom: true,
+        };
+        assert!(SheetSide::Bottom.swipe_scrolls(scrolled));
+        assert!(SheetSide::Top.swipe_scrolls(scrolled));
+        assert!(!SheetSide::Right.swipe_scrolls(scrolled));
+
+        let at_top = ScrollShadows {
+            top: false,
+            bottom: true,
+        };
+        assert!(!SheetSide::Bottom.swipe_scrolls(at_top));
+    }
+
+    #[test]
+    fn swipe_transform() {
+        assert_eq!(
+            SheetSide::Right.transform(20.0),
+            "transform: translateX(20px)"
+        );
+        assert_eq!(
+            SheetSide::Left.transform(20.0),
+            "transform: translateX(-20px)"
+        );
+        assert_eq!(
+            SheetSide::Bottom.transform(20.0),
+            "transform: translateY(20px)"
+        );
+        assert_eq!(
+            SheetSide::Top.transform(20.0),
+            "transform: translateY(-20px)"
+        );
+    }
+}
@@ -0,0 +1,49 @@
+use yew::prelude::*;
+
+use crate::components::DialogContent;
+
+/// The Sheet content has the following props:
+///
+/// Required props:
+///
+/// - `children`: The children to be rendered inside.
+///
+/// Optional props:
+///
+/// - `class`: `yew::Classes`
+/// - `style`: The style attribute.
+#[derive(Debug, PartialEq, Properties)]
+pub struct SheetContentProperties {
+    pub children: Children,
+    #[prop_or_default]
+    pub class: Classes,
+    #[prop_or_default]
+    pub style: Option<AttrValue>,
+}
+
+/// Describes the enclosing [`Sheet`](super::Sheet), as a [`DialogContent`] with its scroll shadows.
+#[derive(Debug)]
+pub struct SheetContent;
+
+impl Component for SheetContent {
+    type Message = ();
+    type Properties = SheetContentProperties;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let Self::Properties {
+            children,
+            class,
+            style,
+        } = ctx.props();
+
+        html! {
+            <DialogContent class={classes!("sheet-content", class.clone())} style={style.clone()}>
+                { children.clone() }
+            </DialogContent>
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use yew::prelude::*;
+
+/// The Sheet footer has the following props:
+///
+/// Required props:
+///
+/// - `children`: The children to be rendered inside.
+///
+/// Optional props:
+///
+/// - `class`: `yew::Classes`
+/// - `style`: The style attribute.
+#[derive(Debug, PartialEq, Properties)]
+pub struct SheetFooterProperties {
+    pub children: Children,
+    #[prop_or_default]
+    pub class: Classes,
+    #[prop_or_default]
+    pub style: Option<AttrValue>,
+}
+
+#[derive(Debug)]
+pub struct SheetFooter;
+
+impl Component for SheetFooter {
+    type Message = ();
+    type Properties = SheetFooterProperties;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let Self::Properties {
+            children,
+            class,
+            style,
+        } = ctx.props();
+
+        html! {
+            <div
+                class={classes!("sheet-footer",
+                    class.clone()
+                )}
+                {style}
+            >
+                { children.clone() }
+            </div>
+        }
+    }
+}
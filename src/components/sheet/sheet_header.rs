@@ -0,0 +1,51 @@
+use yew::prelude::*;
+
+/// The Sheet header has the following props:
+///
+/// Required props:
+///
+/// - `children`: The children to be rendered inside.
+///
+/// Optional props:
+///
+/// - `class`: `yew::Classes`
+/// - `style`: The style attribute.
+#[derive(Debug, PartialEq, Properties)]
+pub struct SheetHeaderProperties {
+    pub children: Children,
+    #[prop_or_default]
+    pub class: Classes,
+    #[prop_or_default]
+    pub style: Option<AttrValue>,
+}
+
+#[derive(Debug)]
+pub struct SheetHeader;
+
+impl Component for SheetHeader {
+    type Message = ();
+    type Properties = SheetHeaderProperties;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let Self::Properties {
+            children,
+            class,
+            style,
+        } = ctx.props();
+
+        html! {
+            <div
+                class={classes!("sheet-header",
+                    class.clone()
+                )}
+                {style}
+            >
+                { children.clone() }
+            </div>
+        }
+    }
+}
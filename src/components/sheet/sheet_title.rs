@@ -0,0 +1,49 @@
+use yew::prelude::*;
+
+use crate::components::DialogTitle;
+
+/// The Sheet title has the following props:
+///
+/// Required props:
+///
+/// - `children`: The children to be rendered inside.
+///
+/// Optional props:
+///
+/// - `class`: `yew::Classes`
+/// - `style`: The style attribute.
+#[derive(Debug, PartialEq, Properties)]
+pub struct SheetTitleProperties {
+    pub children: Children,
+    #[prop_or_default]
+    pub class: Classes,
+    #[prop_or_default]
+    pub style: Option<AttrValue>,
+}
+
+/// Labels the enclosing [`Sheet`](super::Sheet), as a [`DialogTitle`].
+#[derive(Debug)]
+pub struct SheetTitle;
+
+impl Component for SheetTitle {
+    type Message = ();
+    type Properties = SheetTitleProperties;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let Self::Properties {
+            children,
+            class,
+            style,
+        } = ctx.props();
+
+        html! {
+            <DialogTitle class={classes!("sheet-title", class.clone())} style={style.clone()}>
+                { children.clone() }
+            </DialogTitle>
+        }
+    }
+}
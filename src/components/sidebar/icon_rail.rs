@@ -0,0 +1,122 @@
+//! The tooltips and flyout menus shown next to the icon rail of a collapsed sidebar.
+//!
+//! They are `position: fixed`, so that the sidebar, which hides its overflow, doesn't clip them.
+
+use web_sys::Element;
+use yew::prelude::*;
+
+use crate::contexts::{SidebarContext, SidebarSide};
+
+use super::CollapsedMode;
+
+/// The gap between the rail and a tooltip, in pixels.
+const TOOLTIP_GAP: f64 = 8.0;
+
+/// Returns the [`CollapsedMode`] provided by the closest [`SidebarContent`](super::SidebarContent)
+/// or [`SidebarFooter`](super::SidebarFooter).
+pub fn collapsed_mode<C: Component>(ctx: &Context<C>) -> CollapsedMode {
+    ctx.link()
+        .context::<CollapsedMode>(Callback::noop())
+        .map(|(mode, _)| mode)
+        .unwrap_or_default()
+}
+
+/// Whether the component is shown in the icon rail of a collapsed sidebar.
+pub fn is_icon_rail<C: Component>(ctx: &Context<C>) -> bool {
+    let is_open = ctx
+        .link()
+        .context::<SidebarContext>(Callback::noop())
+        .is_some_and(|(sidebar_context, _)| sidebar_context.state.is_expanded());
+
+    !is_open && collapsed_mode(ctx) == CollapsedMode::Icon
+}
+
+/// Returns the side of the sidebar the component is in.
+pub fn sidebar_side<C: Component>(ctx: &Context<C>) -> SidebarSide {
+    ctx.link()
+        .context::<SidebarContext>(Callback::noop())
+        .map(|(sidebar_context, _)| sidebar_context.state.side)
+        .unwrap_or_default()
+}
+
+/// The position of an item of the rail, in viewport coordinates.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RailAnchor {
+    /// The side of the sidebar, the tooltips and flyouts being shown towards the page.
+    pub side: SidebarSide,
+    pub left: f64,
+    pub right: f64,
+    pub top: f64,
+    pub height: f64,
+    pub viewport_width: f64,
+}
+
+impl RailAnchor {
+    pub fn of(element: &Element, side: SidebarSide) -> Self {
+        let rect = element.get_bounding_client_rect();
+        let viewport_width = gloo::utils::document_element().client_width().into();
+
+        Self {
+            side,
+            left: rect.left(),
+            right: rect.right(),
+            top: rect.top(),
+            height: rect.height(),
+            viewport_width,
+        }
+    }
+
+    /// Returns the CSS offset of a popup `gap` pixels away from the item, towards the page.
+    fn inline_offset(self, gap: f64) -> String {
+        match self.side {
+            SidebarSide::Left => format!("left: {}px", self.right + gap),
+            SidebarSide::Right => format!("right: {}px", self.viewport_width - self.left + gap),
+        }
+    }
+
+    /// Returns the style of a tooltip vertically centered next to the item.
+    pub fn tooltip_style(self) -> String {
+        format!(
+            "{}; top: {}px",
+            self.inline_offset(TOOLTIP_GAP),
+            self.top + self.height / 2.0
+        )
+    }
+
+    /// Returns the style of a flyout menu aligned with the top of the item.
+    ///
+    /// It touches the rail, so that moving the pointer to it doesn't leave the group.
+    pub fn flyout_style(self) -> String {
+        format!("{}; top: {}px", self.inline_offset(0.0), self.top)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn styles() {
+        let anchor = RailAnchor {
+            side: SidebarSide::Left,
+            left: 8.0,
+            right: 48.0,
+            top: 100.0,
+            height: 40.0,
+            viewport_width: 1000.0,
+        };
+
+        assert_eq!(anchor.tooltip_style(), "left: 56px; top: 120px");
+        assert_eq!(anchor.flyout_style(), "left: 48px; top: 100px");
+
+        let anchor = RailAnchor {
+            side: SidebarSide::Right,
+            left: 952.0,
+            right: 992.0,
+            ..anchor
+        };
+
+        assert_eq!(anchor.tooltip_style(), "right: 56px; top: 120px");
+        assert_eq!(anchor.flyout_style(), "right: 48px; top: 100px");
+    }
+}
@@ -1,28 +1,73 @@
+mod icon_rail;
 pub mod sidebar_content;
 pub mod sidebar_footer;
+pub mod sidebar_group;
 pub mod sidebar_header;
+pub mod sidebar_item;
+pub mod sidebar_menu;
+pub mod sidebar_menu_button;
+pub mod sidebar_menu_item;
+pub mod sidebar_rail;
 pub mod sidebar_toggle;
 
 pub use sidebar_content::SidebarContent;
 pub use sidebar_footer::SidebarFooter;
+pub use sidebar_group::SidebarGroup;
 pub use sidebar_header::SidebarHeader;
+pub use sidebar_item::SidebarItem;
+pub use sidebar_menu::SidebarMenu;
+pub use sidebar_menu_button::SidebarMenuButton;
+pub use sidebar_menu_item::SidebarMenuItem;
+pub use sidebar_rail::SidebarRail;
 pub use sidebar_toggle::SidebarToggle;
 
 use yew::prelude::*;
 
-use crate::contexts::SidebarContext;
+use crate::contexts::{SidebarAction, SidebarContext, SidebarRegistry, find_sidebar_context};
 
-#[derive(Debug, Default, PartialEq)]
+/// How a part of the sidebar is shown while the sidebar is collapsed.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub enum CollapsedMode {
     #[default]
     Visible,
     Hidden,
+    /// Only the icons of the menu buttons and groups are shown, as a rail. Their labels are shown
+    /// as tooltips, and the groups open as flyout menus next to the rail.
+    Icon,
+}
+
+/// The landmark a [`Sidebar`] is for assistive technologies.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SidebarLandmark {
+    /// A `<nav>`, for the navigation of the site.
+    #[default]
+    Navigation,
+    /// An `<aside>`, for content complementing the page, e.g. details.
+    Complementary,
+}
+
+impl SidebarLandmark {
+    pub fn tag(self) -> &'static str {
+        match self {
+            Self::Navigation => "nav",
+            Self::Complementary => "aside",
+        }
+    }
 }
 
 /// Properties for the [`Sidebar`].
 #[derive(Debug, PartialEq, Properties)]
 pub struct SidebarProperties {
     pub children: Children,
+    /// The id of the [`SidebarProvider`](crate::contexts::SidebarProvider) of the sidebar, the
+    /// closest one if `None`.
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
+    #[prop_or_default]
+    pub landmark: SidebarLandmark,
+    /// The accessible name of the landmark, which tells several sidebars apart.
+    #[prop_or(AttrValue::from("Sidebar"))]
+    pub label: AttrValue,
     #[prop_or_default]
     pub class: Classes,
     #[prop_or_default]
@@ -48,11 +93,20 @@ pub struct SidebarProperties {
 ///     <SidebarFooter>
 ///         { "..." }
 ///     </SidebarFooter>
+///     // Optional
+///     <SidebarRail />
 /// </Sidebar>
 /// ```
+///
+/// It is laid out according to the side and variant of the [`SidebarContext`]. On mobile, it is
+/// shown over the page with a backdrop closing it when clicked.
+///
+/// Its content sees the [`SidebarContext`] of the sidebar with its `id`, so that it works as well
+/// under the provider of another sidebar.
 #[derive(Debug)]
 pub struct Sidebar {
     _ctx_handle: ContextHandle<SidebarContext>,
+    _registry_handle: Option<ContextHandle<SidebarRegistry>>,
 }
 
 impl Component for Sidebar {
@@ -65,33 +119,74 @@ impl Component for Sidebar {
             .context::<SidebarContext>(ctx.link().callback(|_| ()))
             .expect("No sidebar context provided");
 
-        Self { _ctx_handle }
+        let _registry_handle = ctx
+            .link()
+            .context::<SidebarRegistry>(ctx.link().callback(|_| ()))
+            .map(|(_, handle)| handle);
+
+        Self {
+            _ctx_handle,
+            _registry_handle,
+        }
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
-        let (sidebar_context, _) = ctx
-            .link()
-            .context::<SidebarContext>(Callback::noop())
+        let sidebar_context = find_sidebar_context(ctx, ctx.props().id.as_ref())
             .expect("No sidebar context provided");
 
-        let is_open = &sidebar_context.is_open;
+        let is_open = sidebar_context.state.is_expanded();
+        let is_mobile = sidebar_context.state.is_mobile;
+        let width = sidebar_context.state.width;
+        let side = sidebar_context.state.side;
+        let variant = sidebar_context.state.variant;
+        let element_id = sidebar_context.element_id.clone();
 
         let Self::Properties {
             children,
+            landmark,
+            label,
             class,
             style,
             ..
         } = ctx.props();
 
+        // Resized by a `SidebarRail`.
+        let style = match width {
+            Some(width) => Some(format!(
+                "--sidebar-width: {width}px; {}",
+                style.as_deref().unwrap_or_default()
+            )),
+            None => style.as_ref().map(|style| style.to_string()),
+        };
+
+        let close_mobile = {
+            let sidebar_context = sidebar_context.clone();
+            Callback::from(move |_| sidebar_context.dispatch(SidebarAction::Close))
+        };
+
         html! {
-            <aside
-                class={classes!("sidebar",
-                    is_open.then_some("expanded"),
-                    class.clone())}
-                {style}
-            >
-                <div class="sidebar-container">{ children.clone() }</div>
-            </aside>
+            <>
+                if is_mobile && is_open {
+                    <div class="sidebar-backdrop" onclick={close_mobile} />
+                }
+                <@{landmark.tag()}
+                    id={element_id}
+                    aria-label={label.clone()}
+                    class={classes!("sidebar",
+                        side.class(),
+                        variant.class(),
+                        is_mobile.then_some("mobile"),
+                        is_open.then_some("expanded"),
+                        class.clone())}
+                    {style}
+                >
+                    <div class="sidebar-container">
+                        <ContextProvider<SidebarContext> context={sidebar_context}>
+                            { children.clone() }
+                        </ContextProvider<SidebarContext>>
+                    </div>
+                </@>
+            </>
         }
     }
 }
@@ -103,7 +198,13 @@ mod test {
     #[test]
     fn html_with_all_props() {
         let _ = html! {
-            <Sidebar class={classes!("test-class")} style="background-color: red">
+            <Sidebar
+                id="nav"
+                landmark={SidebarLandmark::Complementary}
+                label="Navigation"
+                class={classes!("test-class")}
+                style="background-color: red"
+            >
                 <SidebarHeader>{ "Header" }</SidebarHeader>
                 <SidebarContent collapsible={CollapsedMode::Hidden}>{ "Content" }</SidebarContent>
                 <SidebarFooter collapsible={CollapsedMode::Visible}>{ "Footer" }</SidebarFooter>
@@ -42,7 +42,7 @@ impl Component for SidebarContent {
             .context::<SidebarContext>(Callback::noop())
             .expect("No sidebar context provided");
 
-        let is_open = &sidebar_context.is_open;
+        let is_open = sidebar_context.state.is_expanded();
 
         let Self::Properties {
             children,
@@ -55,11 +55,19 @@ impl Component for SidebarContent {
         let content_class = classes!(
             "sidebar-content",
             (*collapsible == CollapsedMode::Hidden).then_some("collapsed-hidden"),
-            if *is_open { "expanded" } else { "collapsed" },
+            (*collapsible == CollapsedMode::Icon).then_some("collapsed-icon"),
+            if is_open { "expanded" } else { "collapsed" },
             class.clone()
         );
 
-        html! { <div class={content_class} {style}>{ children.clone() }</div> }
+        // Tells the menu buttons and groups inside of it whether to show as an icon rail.
+        html! {
+            <div class={content_class} {style}>
+                <ContextProvider<CollapsedMode> context={*collapsible}>
+                    { children.clone() }
+                </ContextProvider<CollapsedMode>>
+            </div>
+        }
     }
 }
 
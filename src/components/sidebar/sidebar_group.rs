@@ -0,0 +1,342 @@
+use std::fmt;
+
+use web_sys::wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, Node};
+use yew::prelude::*;
+use yew_router::prelude::*;
+use yew_router::scope_ext::LocationHandle;
+
+use crate::contexts::{SidebarAction, SidebarContext};
+
+use super::icon_rail::{RailAnchor, is_icon_rail, sidebar_side};
+
+/// Properties for the [`SidebarGroup`].
+#[derive(Debug, PartialEq, Properties)]
+pub struct SidebarGroupProperties {
+    pub children: Children,
+    /// Identifies the group in the [`SidebarContext`], where whether it is expanded is stored.
+    pub id: AttrValue,
+    pub label: AttrValue,
+    /// Shown before the label, and alone while the sidebar is collapsed.
+    #[prop_or_default]
+    pub icon: Html,
+    #[prop_or_default]
+    pub class: Classes,
+    #[prop_or_default]
+    pub style: Option<AttrValue>,
+}
+
+#[derive(Debug)]
+pub enum SidebarGroupMessage {
+    /// The sidebar changed.
+    Changed,
+    /// The location changed, so the group may now contain the current page.
+    LocationChanged,
+    TriggerClick,
+    PointerEnter,
+    PointerLeave,
+    FocusOut(FocusEvent),
+    KeyDown(KeyboardEvent),
+}
+
+/// A collapsible group of [`SidebarMenuItem`](super::SidebarMenuItem)s, which can be nested.
+///
+/// Whether it is expanded is stored in the [`SidebarContext`] by its `id`, and persisted with the
+/// open state of the sidebar. It expands by itself when navigating to one of its pages.
+///
+/// In the icon rail of [`CollapsedMode::Icon`](super::CollapsedMode::Icon), it rather opens as a
+/// flyout menu next to the rail when hovered or clicked, which is closed by Escape.
+///
+/// Usage:
+/// ```ignore
+/// <SidebarMenu>
+///     <SidebarMenuItem>
+///         <SidebarGroup
+///             id="overlays"
+///             label="Overlays"
+///             // Optional
+///             icon={html! { <LayersIcon /> }}
+///         >
+///             <SidebarMenu>
+///                 <SidebarMenuItem>
+///                     <SidebarMenuButton<Route> to={Route::Dialog} label="Dialog" />
+///                 </SidebarMenuItem>
+///             </SidebarMenu>
+///         </SidebarGroup>
+///     </SidebarMenuItem>
+/// </SidebarMenu>
+/// ```
+pub struct SidebarGroup {
+    node_ref: NodeRef,
+    trigger_ref: NodeRef,
+    content_ref: NodeRef,
+    /// Whether to expand the group if it contains the current page once rendered.
+    check_active: bool,
+    /// Where the flyout menu is open.
+    flyout: Option<RailAnchor>,
+    was_rail: bool,
+    _ctx_handle: ContextHandle<SidebarContext>,
+    _location_handle: Option<LocationHandle>,
+}
+
+// The location handle isn't `Debug`.
+impl fmt::Debug for SidebarGroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SidebarGroup")
+            .field("check_active", &self.check_active)
+            .field("flyout", &self.flyout)
+            .field("was_rail", &self.was_rail)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SidebarGroup {
+    fn open_flyout(&mut self, ctx: &Context<Self>) {
+        if self.flyout.is_none() {
+            self.flyout = self
+                .trigger_ref
+                .cast::<Element>()
+                .map(|t| RailAnchor::of(&t, sidebar_side(ctx)));
+        }
+    }
+
+    /// Renders the group in the icon rail, with its items in a flyout menu.
+    fn view_rail(&self, ctx: &Context<Self>) -> Html {
+        let (sidebar_context, _) = ctx
+            .link()
+            .context::<SidebarContext>(Callback::noop())
+            .expect("No sidebar context provided");
+
+        let SidebarGroupProperties {
+            children,
+            id,
+            label,
+            icon,
+            class,
+            style,
+        } = ctx.props();
+
+        let content_id = format!("sidebar-group-{id}");
+
+        html! {
+            <div
+                class={classes!("sidebar-group", self.flyout.is_some().then_some("flyout-open"),
+                    class.clone()
+                )}
+                {style}
+                ref={self.node_ref.clone()}
+                onmouseenter={ctx.link().callback(|_| SidebarGroupMessage::PointerEnter)}
+                onmouseleave={ctx.link().callback(|_| SidebarGroupMessage::PointerLeave)}
+                onfocusout={ctx.link().callback(SidebarGroupMessage::FocusOut)}
+                onkeydown={ctx.link().callback(SidebarGroupMessage::KeyDown)}
+            >
+                <button
+                    class="sidebar-group-trigger collapsed"
+                    aria-expanded={self.flyout.is_some().to_string()}
+                    aria-controls={content_id.clone()}
+                    aria-haspopup="true"
+                    aria-label={label.clone()}
+                    ref={self.trigger_ref.clone()}
+                    onclick={ctx.link().callback(|_| SidebarGroupMessage::TriggerClick)}
+                >
+                    <span class="sidebar-menu-icon" aria-hidden="true">{ icon.clone() }</span>
+                </button>
+                if let Some(anchor) = self.flyout {
+                    <div
+                        id={content_id}
+                        class={classes!("sidebar-flyout", anchor.side.class())}
+                        style={anchor.flyout_style()}
+                    >
+                        <div class="sidebar-flyout-inner">
+                            <div class="sidebar-flyout-label">{ label.clone() }</div>
+                            // The items are shown with their labels, as in an expanded sidebar.
+                            <ContextProvider<SidebarContext> context={sidebar_context.expanded()}>
+                                { children.clone() }
+                            </ContextProvider<SidebarContext>>
+                        </div>
+                    </div>
+                }
+            </div>
+        }
+    }
+}
+
+impl Component for SidebarGroup {
+    type Message = SidebarGroupMessage;
+    type Properties = SidebarGroupProperties;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let (_, ctx_handle) = ctx
+            .link()
+            .context::<SidebarContext>(ctx.link().callback(|_| SidebarGroupMessage::Changed))
+            .expect("No sidebar context provided");
+        let location_handle = ctx.link().add_location_listener(
+            ctx.link()
+                .callback(|_| SidebarGroupMessage::LocationChanged),
+        );
+
+        Self {
+            node_ref: NodeRef::default(),
+            trigger_ref: NodeRef::default(),
+            content_ref: NodeRef::default(),
+            check_active: true,
+            flyout: None,
+            was_rail: is_icon_rail(ctx),
+            _ctx_handle: ctx_handle,
+            _location_handle: location_handle,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            SidebarGroupMessage::Changed => {
+                let rail = is_icon_rail(ctx);
+                if !rail {
+                    self.flyout = None;
+                    // Leaving the rail, where the group was not expanded by navigation.
+                    self.check_active |= self.was_rail;
+                }
+                self.was_rail = rail;
+            }
+            SidebarGroupMessage::LocationChanged => {
+                self.check_active = true;
+                self.flyout = None;
+            }
+            SidebarGroupMessage::TriggerClick if is_icon_rail(ctx) => self.open_flyout(ctx),
+            SidebarGroupMessage::TriggerClick => {
+                if let Some((sidebar_context, _)) =
+                    ctx.link().context::<SidebarContext>(Callback::noop())
+                {
+                    sidebar_context.dispatch(SidebarAction::ToggleGroup(ctx.props().id.clone()));
+                }
+                return false;
+            }
+            SidebarGroupMessage::PointerEnter if is_icon_rail(ctx) => self.open_flyout(ctx),
+            SidebarGroupMessage::PointerEnter => return false,
+            SidebarGroupMessage::PointerLeave => return self.flyout.take().is_some(),
+            SidebarGroupMessage::FocusOut(e) => {
+                let inside = e.related_target().is_some_and(|target| {
+                    self.node_ref
+                        .cast::<Node>()
+                        .is_some_and(|node| node.contains(target.dyn_ref::<Node>()))
+                });
+                return !inside && self.flyout.take().is_some();
+            }
+            SidebarGroupMessage::KeyDown(e) => {
+                if e.key() != "Escape" || self.flyout.is_none() {
+                    return false;
+                }
+
+                e.stop_propagation();
+                self.flyout = None;
+                if let Some(trigger) = self.trigger_ref.cast::<HtmlElement>() {
+                    let _ = trigger.focus();
+                }
+            }
+        }
+
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        if is_icon_rail(ctx) {
+            return self.view_rail(ctx);
+        }
+
+        let (sidebar_context, _) = ctx
+            .link()
+            .context::<SidebarContext>(Callback::noop())
+            .expect("No sidebar context provided");
+
+        let is_open = sidebar_context.state.is_expanded();
+
+        let Self::Properties {
+            children,
+            id,
+            label,
+            icon,
+            class,
+            style,
+        } = ctx.props();
+
+        let expanded = sidebar_context.state.is_group_expanded(id);
+        let content_id = format!("sidebar-group-{id}");
+
+        html! {
+            <div
+                class={classes!("sidebar-group", expanded.then_some("group-expanded"), class.clone())}
+                {style}
+            >
+                <button
+                    class={classes!("sidebar-group-trigger",
+                        if is_open { "expanded" } else { "collapsed" }
+                    )}
+                    aria-expanded={expanded.to_string()}
+                    aria-controls={content_id.clone()}
+                    aria-label={(!is_open).then(|| label.clone())}
+                    title={(!is_open).then(|| label.clone())}
+                    onclick={ctx.link().callback(|_| SidebarGroupMessage::TriggerClick)}
+                >
+                    <span class="sidebar-menu-icon" aria-hidden="true">{ icon.clone() }</span>
+                    <span class="sidebar-menu-label">{ label.clone() }</span>
+                    <span class="sidebar-group-chevron" aria-hidden="true" />
+                </button>
+                // Collapsed groups stay rendered, to animate their height and to find the current
+                // page in them.
+                <div
+                    id={content_id}
+                    class="sidebar-group-content"
+                    inert={(!expanded).then_some("")}
+                    ref={self.content_ref.clone()}
+                >
+                    <div class="sidebar-group-inner">{ children.clone() }</div>
+                </div>
+            </div>
+        }
+    }
+
+    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
+        if !std::mem::take(&mut self.check_active) {
+            return;
+        }
+
+        let contains_active = self
+            .content_ref
+            .cast::<Element>()
+            .and_then(|content| content.query_selector("[aria-current=\"page\"]").ok())
+            .flatten()
+            .is_some();
+        if contains_active
+            && let Some((sidebar_context, _)) =
+                ctx.link().context::<SidebarContext>(Callback::noop())
+        {
+            sidebar_context.dispatch(SidebarAction::ExpandGroup(ctx.props().id.clone()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::components::{SidebarMenu, SidebarMenuButton, SidebarMenuItem};
+    use crate::route::Route;
+
+    #[test]
+    fn html_with_all_props() {
+        let _ = html! {
+            <SidebarGroup
+                id="overlays"
+                label="Overlays"
+                icon={html! { { "O" } }}
+                class={classes!("test-class")}
+                style="background-color: red"
+            >
+                <SidebarMenu>
+                    <SidebarMenuItem>
+                        <SidebarMenuButton<Route> to={Route::Dialog} label="Dialog" />
+                    </SidebarMenuItem>
+                </SidebarMenu>
+            </SidebarGroup>
+        };
+    }
+}
@@ -16,6 +16,7 @@ pub struct SidebarItemProperties {
     pub style: Option<AttrValue>,
 }
 
+/// A block of the sidebar, which can be hidden while the sidebar is collapsed.
 #[derive(Debug)]
 pub struct SidebarItem {
     _ctx_handle: ContextHandle<SidebarContext>,
@@ -42,7 +43,7 @@ impl Component for SidebarItem {
             .context::<SidebarContext>(Callback::noop())
             .expect("No sidebar context provided");
 
-        let open = &sidebar_context.state.open;
+        let is_open = sidebar_context.state.is_expanded();
 
         let Self::Properties {
             children,
@@ -52,13 +53,16 @@ impl Component for SidebarItem {
             ..
         } = ctx.props();
 
-        let footer_class = classes!(
-            (*collapsible == CollapsedMode::Hidden).then_some("collapsed-hidden"),
-            if *open { "expanded" } else { "collapsed" },
+        let item_class = classes!(
+            "sidebar-item",
+            // It has no icon to show in the rail.
+            matches!(collapsible, CollapsedMode::Hidden | CollapsedMode::Icon)
+                .then_some("collapsed-hidden"),
+            if is_open { "expanded" } else { "collapsed" },
             class.clone()
         );
 
-        html! { <div class={footer_class} {style}>{ children.clone() }</div> }
+        html! { <div class={item_class} {style}>{ children.clone() }</div> }
     }
 }
 
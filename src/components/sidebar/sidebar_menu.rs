@@ -0,0 +1,51 @@
+use yew::prelude::*;
+
+/// Properties for the [`SidebarMenu`].
+#[derive(Debug, PartialEq, Properties)]
+pub struct SidebarMenuProperties {
+    pub children: Children,
+    #[prop_or_default]
+    pub class: Classes,
+    #[prop_or_default]
+    pub style: Option<AttrValue>,
+}
+
+/// A list of [`SidebarMenuItem`](super::SidebarMenuItem)s.
+#[derive(Debug)]
+pub struct SidebarMenu;
+
+impl Component for SidebarMenu {
+    type Message = ();
+    type Properties = SidebarMenuProperties;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let Self::Properties {
+            children,
+            class,
+            style,
+        } = ctx.props();
+
+        html! {
+            <ul class={classes!("sidebar-menu", class.clone())} {style}>{ children.clone() }</ul>
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::components::SidebarMenuItem;
+
+    #[test]
+    fn html_with_all_props() {
+        let _ = html! {
+            <SidebarMenu class={classes!("test-class")} style="background-color: red">
+                <SidebarMenuItem>{ "Item" }</SidebarMenuItem>
+            </SidebarMenu>
+        };
+    }
+}
@@ -0,0 +1,233 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use web_sys::Element;
+use yew::prelude::*;
+use yew_router::prelude::*;
+use yew_router::scope_ext::LocationHandle;
+
+use crate::contexts::SidebarContext;
+
+use super::icon_rail::{RailAnchor, is_icon_rail, sidebar_side};
+
+/// Whether the page at `current` is the one at `target`, or one of its sub-pages if not `exact`.
+pub fn is_active(current: &str, target: &str, exact: bool) -> bool {
+    let prefix = format!("{}/", target.trim_end_matches('/'));
+
+    current == target || (!exact && current.starts_with(&prefix))
+}
+
+/// Properties for the [`SidebarMenuButton`].
+#[derive(Debug, PartialEq, Properties)]
+pub struct SidebarMenuButtonProperties<R: Routable + 'static> {
+    /// The route navigated to.
+    pub to: R,
+    pub label: AttrValue,
+    /// Shown before the label, and alone while the sidebar is collapsed.
+    #[prop_or_default]
+    pub icon: Html,
+    /// Whether the button is only highlighted on its route, rather than on its sub-routes as well.
+    #[prop_or(true)]
+    pub exact: bool,
+    #[prop_or_default]
+    pub class: Classes,
+    #[prop_or_default]
+    pub style: Option<AttrValue>,
+}
+
+#[derive(Debug)]
+pub enum SidebarMenuButtonMessage {
+    /// The sidebar or the location changed.
+    Changed,
+    Click(MouseEvent),
+    /// The button was hovered or focused.
+    ShowTooltip,
+    HideTooltip,
+}
+
+/// A link to a route in a [`SidebarMenuItem`](super::SidebarMenuItem), highlighted while the
+/// route is the current one.
+///
+/// While the sidebar is collapsed, only its icon is shown and the label becomes its tooltip. In
+/// [`CollapsedMode::Icon`](super::CollapsedMode::Icon), the tooltip is shown next to the rail on
+/// hover and focus.
+///
+/// Usage:
+/// ```ignore
+/// <SidebarMenu>
+///     <SidebarMenuItem>
+///         <SidebarMenuButton<Route>
+///             to={Route::Home}
+///             label="Home"
+///             // Optional
+///             icon={html! { <HomeIcon /> }}
+///         />
+///     </SidebarMenuItem>
+/// </SidebarMenu>
+/// ```
+pub struct SidebarMenuButton<R: Routable + 'static> {
+    node_ref: NodeRef,
+    tooltip: Option<RailAnchor>,
+    _ctx_handle: ContextHandle<SidebarContext>,
+    _location_handle: Option<LocationHandle>,
+    _marker: PhantomData<R>,
+}
+
+// The location handle isn't `Debug`.
+impl<R: Routable + 'static> fmt::Debug for SidebarMenuButton<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SidebarMenuButton")
+            .field("node_ref", &self.node_ref)
+            .field("tooltip", &self.tooltip)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R: Routable + 'static> Component for SidebarMenuButton<R> {
+    type Message = SidebarMenuButtonMessage;
+    type Properties = SidebarMenuButtonProperties<R>;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let (_, ctx_handle) = ctx
+            .link()
+            .context::<SidebarContext>(ctx.link().callback(|_| SidebarMenuButtonMessage::Changed))
+            .expect("No sidebar context provided");
+        let location_handle = ctx
+            .link()
+            .add_location_listener(ctx.link().callback(|_| SidebarMenuButtonMessage::Changed));
+
+        Self {
+            node_ref: NodeRef::default(),
+            tooltip: None,
+            _ctx_handle: ctx_handle,
+            _location_handle: location_handle,
+            _marker: PhantomData,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            SidebarMenuButtonMessage::Changed => {
+                if !is_icon_rail(ctx) {
+                    self.tooltip = None;
+                }
+
+                true
+            }
+            SidebarMenuButtonMessage::Click(e) => {
+                // Modified clicks are left to the browser, e.g. to open the page in a new tab.
+                let is_plain = e.button() == 0
+                    && !(e.ctrl_key() || e.meta_key() || e.shift_key() || e.alt_key());
+                if let Some(navigator) = ctx.link().navigator().filter(|_| is_plain) {
+                    e.prevent_default();
+                    navigator.push(&ctx.props().to);
+                }
+
+                false
+            }
+            SidebarMenuButtonMessage::ShowTooltip => {
+                if !is_icon_rail(ctx) {
+                    return false;
+                }
+
+                self.tooltip = self
+                    .node_ref
+                    .cast::<Element>()
+                    .map(|a| RailAnchor::of(&a, sidebar_side(ctx)));
+                true
+            }
+            SidebarMenuButtonMessage::HideTooltip => self.tooltip.take().is_some(),
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let (sidebar_context, _) = ctx
+            .link()
+            .context::<SidebarContext>(Callback::noop())
+            .expect("No sidebar context provided");
+
+        let is_open = sidebar_context.state.is_expanded();
+        let is_rail = is_icon_rail(ctx);
+
+        let Self::Properties {
+            to,
+            label,
+            icon,
+            exact,
+            class,
+            style,
+        } = ctx.props();
+
+        let target = to.to_path();
+        let active = ctx
+            .link()
+            .route::<R>()
+            .is_some_and(|current| is_active(&current.to_path(), &target, *exact));
+        let href = match ctx.link().navigator().as_ref().and_then(|n| n.basename()) {
+            Some(basename) => format!("{}{target}", basename.trim_end_matches('/')),
+            None => target,
+        };
+
+        html! {
+            <a
+                class={classes!("sidebar-menu-button",
+                    active.then_some("active"),
+                    if is_open { "expanded" } else { "collapsed" },
+                    class.clone()
+                )}
+                {href}
+                {style}
+                aria-current={active.then_some("page")}
+                aria-label={(!is_open).then(|| label.clone())}
+                title={(!is_open && !is_rail).then(|| label.clone())}
+                ref={self.node_ref.clone()}
+                onclick={ctx.link().callback(SidebarMenuButtonMessage::Click)}
+                onmouseenter={ctx.link().callback(|_| SidebarMenuButtonMessage::ShowTooltip)}
+                onmouseleave={ctx.link().callback(|_| SidebarMenuButtonMessage::HideTooltip)}
+                onfocus={ctx.link().callback(|_| SidebarMenuButtonMessage::ShowTooltip)}
+                onblur={ctx.link().callback(|_| SidebarMenuButtonMessage::HideTooltip)}
+            >
+                <span class="sidebar-menu-icon" aria-hidden="true">{ icon.clone() }</span>
+                <span class="sidebar-menu-label">{ label.clone() }</span>
+                // Already the label of the button.
+                if let Some(anchor) = self.tooltip.filter(|_| is_rail) {
+                    <span class="sidebar-tooltip" role="tooltip" aria-hidden="true"
+                        style={anchor.tooltip_style()}
+                    >
+                        { label.clone() }
+                    </span>
+                }
+            </a>
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::route::Route;
+
+    #[test]
+    fn html_with_all_props() {
+        let _ = html! {
+            <SidebarMenuButton<Route>
+                to={Route::Dialog}
+                label="Dialog"
+                icon={html! { { "D" } }}
+                exact=false
+                class={classes!("test-class")}
+                style="background-color: red"
+            />
+        };
+    }
+
+    #[test]
+    fn active_route() {
+        assert!(is_active("/", "/", true));
+        assert!(!is_active("/dialog", "/", true));
+        assert!(is_active("/dialog", "/", false));
+        assert!(is_active("/settings/profile", "/settings", false));
+        assert!(!is_active("/settings/profile", "/settings", true));
+        assert!(!is_active("/settings-old", "/settings", false));
+    }
+}
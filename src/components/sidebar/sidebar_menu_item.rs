@@ -0,0 +1,51 @@
+use yew::prelude::*;
+
+/// Properties for the [`SidebarMenuItem`].
+#[derive(Debug, PartialEq, Properties)]
+pub struct SidebarMenuItemProperties {
+    pub children: Children,
+    #[prop_or_default]
+    pub class: Classes,
+    #[prop_or_default]
+    pub style: Option<AttrValue>,
+}
+
+/// An item of a [`SidebarMenu`](super::SidebarMenu), usually holding a
+/// [`SidebarMenuButton`](super::SidebarMenuButton).
+#[derive(Debug)]
+pub struct SidebarMenuItem;
+
+impl Component for SidebarMenuItem {
+    type Message = ();
+    type Properties = SidebarMenuItemProperties;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let Self::Properties {
+            children,
+            class,
+            style,
+        } = ctx.props();
+
+        html! {
+            <li class={classes!("sidebar-menu-item", class.clone())} {style}>{ children.clone() }</li>
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn html_with_all_props() {
+        let _ = html! {
+            <SidebarMenuItem class={classes!("test-class")} style="background-color: red">
+                { "Item" }
+            </SidebarMenuItem>
+        };
+    }
+}
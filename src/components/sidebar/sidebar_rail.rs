@@ -0,0 +1,334 @@
+use web_sys::Element;
+use yew::prelude::*;
+
+use crate::contexts::{SidebarAction, SidebarContext, SidebarSide};
+
+/// What dragging or pressing a key on the [`SidebarRail`] does to the sidebar.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Resize {
+    /// The sidebar was made narrower than the collapse threshold.
+    Collapse,
+    /// The sidebar is resized to the width, within its bounds.
+    Width(f64),
+}
+
+impl Resize {
+    pub fn new(width: f64, props: &SidebarRailProperties) -> Self {
+        if width < props.collapse_threshold {
+            Self::Collapse
+        } else {
+            Self::Width(width.clamp(props.min_width, props.max_width))
+        }
+    }
+
+    /// Returns the resize by `delta` pixels from `width` with the keyboard.
+    ///
+    /// Narrowing the sidebar at its minimum width collapses it, and widening the collapsed sidebar
+    /// expands it at its minimum width, since the steps are smaller than the collapse threshold.
+    pub fn by_key(width: f64, delta: f64, props: &SidebarRailProperties) -> Self {
+        // The rendered width can be a fraction of a pixel off.
+        let at_min = width < props.min_width + 0.5;
+        if delta < 0.0 && at_min {
+            Self::Collapse
+        } else if delta > 0.0 && at_min {
+            Self::Width(props.min_width.max(width + delta).min(props.max_width))
+        } else {
+            Self::new(width + delta, props)
+        }
+    }
+}
+
+/// Properties for the [`SidebarRail`].
+#[derive(Debug, PartialEq, Properties)]
+pub struct SidebarRailProperties {
+    /// The narrowest the expanded sidebar can be, in pixels.
+    #[prop_or(192.0)]
+    pub min_width: f64,
+    /// The widest the sidebar can be, in pixels.
+    #[prop_or(480.0)]
+    pub max_width: f64,
+    /// The width in pixels under which the sidebar collapses.
+    #[prop_or(120.0)]
+    pub collapse_threshold: f64,
+    /// How much the arrow keys resize the sidebar, in pixels.
+    #[prop_or(16.0)]
+    pub step: f64,
+    #[prop_or(AttrValue::from("Resize sidebar"))]
+    pub label: AttrValue,
+    #[prop_or_default]
+    pub class: Classes,
+    #[prop_or_default]
+    pub style: Option<AttrValue>,
+}
+
+#[derive(Debug)]
+pub enum SidebarRailMessage {
+    /// The sidebar changed.
+    Changed,
+    PointerDown(PointerEvent),
+    PointerMove(PointerEvent),
+    PointerUp,
+    KeyDown(KeyboardEvent),
+    KeyUp,
+}
+
+/// A dragging pointer.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Drag {
+    pointer_id: i32,
+    start_x: f64,
+    start_width: f64,
+}
+
+/// A handle on the edge of the [`Sidebar`](super::Sidebar) to resize it, by dragging it or with
+/// the arrow keys once focused.
+///
+/// The width is stored in the [`SidebarContext`] while resizing, and persisted with its open state
+/// once the pointer or the key is released. Making it narrower than `collapse_threshold` collapses
+/// the sidebar, as does narrowing it at `min_width` with the keyboard, and widening the collapsed
+/// sidebar expands it again. It is not shown on mobile.
+///
+/// Usage:
+/// ```ignore
+/// <Sidebar>
+///     { "..." }
+///     <SidebarRail
+///         // Optional
+///         min_width=240.0
+///         max_width=640.0
+///     />
+/// </Sidebar>
+/// ```
+#[derive(Debug)]
+pub struct SidebarRail {
+    node_ref: NodeRef,
+    drag: Option<Drag>,
+    _ctx_handle: ContextHandle<SidebarContext>,
+}
+
+impl SidebarRail {
+    /// Returns the current width of the sidebar in pixels.
+    fn sidebar_width(&self) -> Option<f64> {
+        self.node_ref
+            .cast::<Element>()
+            .and_then(|rail| rail.closest(".sidebar").ok().flatten())
+            .map(|sidebar| sidebar.get_bounding_client_rect().width())
+    }
+
+    fn resize(ctx: &Context<Self>, resize: Resize) {
+        let Some((sidebar_context, _)) = ctx.link().context::<SidebarContext>(Callback::noop())
+        else {
+            return;
+        };
+
+        sidebar_context.dispatch(SidebarAction::SetResizing(true));
+        match resize {
+            Resize::Collapse => sidebar_context.dispatch(SidebarAction::Close),
+            Resize::Width(width) => {
+                sidebar_context.dispatch(SidebarAction::Open);
+                sidebar_context.dispatch(SidebarAction::SetWidth(width));
+            }
+        }
+    }
+
+    /// Ends resizing the sidebar, which persists its width.
+    fn end_resize(ctx: &Context<Self>) {
+        if let Some((sidebar_context, _)) = ctx.link().context::<SidebarContext>(Callback::noop()) {
+            sidebar_context.dispatch(SidebarAction::SetResizing(false));
+        }
+    }
+
+    /// Disables the transitions of the sidebar and keeps the resize cursor over the whole page.
+    fn set_page_resizing(resizing: bool) {
+        let class_list = gloo::utils::body().class_list();
+        let _ = if resizing {
+            class_list.add_1("sidebar-resizing")
+        } else {
+            class_list.remove_1("sidebar-resizing")
+        };
+    }
+}
+
+impl Component for SidebarRail {
+    type Message = SidebarRailMessage;
+    type Properties = SidebarRailProperties;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        let (_, ctx_handle) = ctx
+            .link()
+            .context::<SidebarContext>(ctx.link().callback(|_| SidebarRailMessage::Changed))
+            .expect("No sidebar context provided");
+
+        Self {
+            node_ref: NodeRef::default(),
+            drag: None,
+            _ctx_handle: ctx_handle,
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        let props = ctx.props();
+        // Towards the page widens the sidebar.
+        let direction = match ctx.link().context::<SidebarContext>(Callback::noop()) {
+            Some((sidebar_context, _)) if sidebar_context.state.side == SidebarSide::Right => -1.0,
+            _ => 1.0,
+        };
+
+        match msg {
+            SidebarRailMessage::Changed => true,
+            SidebarRailMessage::PointerDown(e) => {
+                let Some(start_width) = self.sidebar_width().filter(|_| e.button() == 0) else {
+                    return false;
+                };
+
+                e.prevent_default();
+                if let Some(target) = e.target_dyn_into::<Element>() {
+                    let _ = target.set_pointer_capture(e.pointer_id());
+                }
+                Self::set_page_resizing(true);
+                self.drag = Some(Drag {
+                    pointer_id: e.pointer_id(),
+                    start_x: e.client_x().into(),
+                    start_width,
+                });
+
+                true
+            }
+            SidebarRailMessage::PointerMove(e) => {
+                let Some(drag) = self.drag.filter(|d| d.pointer_id == e.pointer_id()) else {
+                    return false;
+                };
+
+                let dx = f64::from(e.client_x()) - drag.start_x;
+                Self::resize(ctx, Resize::new(drag.start_width + direction * dx, props));
+                false
+            }
+            SidebarRailMessage::PointerUp => {
+                Self::set_page_resizing(false);
+                Self::end_resize(ctx);
+                self.drag.take().is_some()
+            }
+            SidebarRailMessage::KeyDown(e) => {
+                let Some(width) = self.sidebar_width() else {
+                    return false;
+                };
+
+                let resize = match e.key().as_str() {
+                    "ArrowLeft" => Resize::by_key(width, -direction * props.step, props),
+                    "ArrowRight" => Resize::by_key(width, direction * props.step, props),
+                    "Home" => Resize::Width(props.min_width),
+                    "End" => Resize::Width(props.max_width),
+                    _ => return false,
+                };
+                e.prevent_default();
+                Self::resize(ctx, resize);
+
+                false
+            }
+            SidebarRailMessage::KeyUp => {
+                Self::end_resize(ctx);
+                false
+            }
+        }
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let (sidebar_context, _) = ctx
+            .link()
+            .context::<SidebarContext>(Callback::noop())
+            .expect("No sidebar context provided");
+
+        let Self::Properties {
+            min_width,
+            max_width,
+            label,
+            class,
+            style,
+            ..
+        } = ctx.props();
+
+        if sidebar_context.state.is_mobile {
+            return html! {};
+        }
+
+        html! {
+            <div
+                class={classes!("sidebar-rail", self.drag.is_some().then_some("resizing"),
+                    class.clone()
+                )}
+                {style}
+                role="separator"
+                aria-orientation="vertical"
+                aria-label={label.clone()}
+                aria-valuemin={min_width.to_string()}
+                aria-valuemax={max_width.to_string()}
+                aria-valuenow={sidebar_context.state.width.map(|width| width.round().to_string())}
+                tabindex="0"
+                ref={self.node_ref.clone()}
+                onpointerdown={ctx.link().callback(SidebarRailMessage::PointerDown)}
+                onpointermove={ctx.link().callback(SidebarRailMessage::PointerMove)}
+                onpointerup={ctx.link().callback(|_| SidebarRailMessage::PointerUp)}
+                onpointercancel={ctx.link().callback(|_| SidebarRailMessage::PointerUp)}
+                onkeydown={ctx.link().callback(SidebarRailMessage::KeyDown)}
+                onkeyup={ctx.link().callback(|_| SidebarRailMessage::KeyUp)}
+            />
+        }
+    }
+
+    fn destroy(&mut self, ctx: &Context<Self>) {
+        if self.drag.is_some() {
+            Self::set_page_resizing(false);
+            Self::end_resize(ctx);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn html_with_all_props() {
+        let _ = html! {
+            <SidebarRail
+                min_width=240.0
+                max_width=640.0
+                collapse_threshold=160.0
+                step=32.0
+                label="Resize navigation"
+                class={classes!("test-class")}
+                style="background-color: red"
+            />
+        };
+    }
+
+    #[test]
+    fn resize() {
+        let props = yew::props!(SidebarRailProperties {});
+
+        assert_eq!(Resize::new(300.0, &props), Resize::Width(300.0));
+        assert_eq!(Resize::new(150.0, &props), Resize::Width(192.0));
+        assert_eq!(Resize::new(100.0, &props), Resize::Collapse);
+        assert_eq!(Resize::new(900.0, &props), Resize::Width(480.0));
+    }
+
+    #[test]
+    fn resize_by_key() {
+        let props = yew::props!(SidebarRailProperties {});
+
+        assert_eq!(Resize::by_key(300.0, -16.0, &props), Resize::Width(284.0));
+        assert_eq!(Resize::by_key(200.0, -16.0, &props), Resize::Width(192.0));
+        assert_eq!(
+            Resize::by_key(192.0, -16.0, &props),
+            Resize::Collapse,
+            "narrowing at the minimum width collapses"
+        );
+        assert_eq!(
+            Resize::by_key(48.0, 16.0, &props),
+            Resize::Width(192.0),
+            "widening the collapsed sidebar expands it"
+        );
+        assert_eq!(Resize::by_key(192.0, 16.0, &props), Resize::Width(208.0));
+        assert_eq!(Resize::by_key(476.0, 16.0, &props), Resize::Width(480.0));
+    }
+}
@@ -1,47 +1,134 @@
+use std::rc::Rc;
+
+use yew::html::onclick;
 use yew::prelude::*;
+use yew::virtual_dom::{VNode, VTag};
 
-use crate::contexts::{SidebarAction, SidebarContext};
+use crate::contexts::{SidebarAction, SidebarContext, SidebarRegistry, find_sidebar_context};
 
 /// Properties for the [`SidebarToggle`].
 #[derive(Debug, PartialEq, Properties)]
 pub struct SidebarToggleProperties {
     #[prop_or_default]
     pub children: Children,
+    /// The id of the [`SidebarProvider`](crate::contexts::SidebarProvider) of the sidebar to
+    /// toggle, the closest one if `None`.
+    #[prop_or_default]
+    pub target: Option<AttrValue>,
+    /// Renders the child elements, e.g. a styled `<button>`, as the toggle rather than a
+    /// `<button>` around the children. Components are rendered as they are, they can rather use
+    /// [`use_sidebar`](crate::contexts::use_sidebar) to toggle the sidebar.
+    #[prop_or_default]
+    pub as_child: bool,
+    /// The accessible name of the toggle, if its children have no text.
+    #[prop_or_default]
+    pub label: Option<AttrValue>,
     #[prop_or_default]
     pub class: Classes,
     #[prop_or_default]
     pub style: Option<AttrValue>,
 }
 
-/// A component to represent a toggle button for [`Sidebar`] component.
+/// Returns the elements of `children` with the ARIA attributes and the click listener of a toggle.
+fn with_toggle_attributes(
+    children: &Children,
+    expanded: bool,
+    controls: &AttrValue,
+    onclick: &Callback<MouseEvent>,
+) -> Html {
+    children
+        .iter()
+        .map(|child| match child {
+            VNode::VTag(tag) => {
+                let mut tag = VTag::clone(&tag);
+                tag.add_attribute("aria-expanded", expanded.to_string());
+                tag.add_attribute("aria-controls", controls.clone());
+                tag.add_listener(Rc::new(onclick::Wrapper::new(onclick.clone())));
+                tag.into()
+            }
+            child => {
+                log::warn!(
+                    "SidebarToggle with `as_child` only turns elements into toggles, \
+                     use `use_sidebar` in components"
+                );
+                child
+            }
+        })
+        .collect()
+}
+
+/// A component to represent a toggle button for [`Sidebar`](super::Sidebar) component.
+///
+/// It tells assistive technologies whether the sidebar is expanded, and which element it controls.
+///
+/// Usage:
+/// ```ignore
+/// <SidebarToggle>{ "Menu" }</SidebarToggle>
+///
+/// // As a custom element
+/// <SidebarToggle as_child=true>
+///     <button class="icon-button" aria-label="Menu">{ "☰" }</button>
+/// </SidebarToggle>
+/// ```
 #[derive(Debug)]
-pub struct SidebarToggle;
+pub struct SidebarToggle {
+    _ctx_handle: Option<ContextHandle<SidebarContext>>,
+    _registry_handle: Option<ContextHandle<SidebarRegistry>>,
+}
 
 impl Component for SidebarToggle {
     type Message = ();
     type Properties = SidebarToggleProperties;
 
-    fn create(_ctx: &Context<Self>) -> Self {
-        Self
+    fn create(ctx: &Context<Self>) -> Self {
+        let _ctx_handle = ctx
+            .link()
+            .context::<SidebarContext>(ctx.link().callback(|_| ()))
+            .map(|(_, handle)| handle);
+        let _registry_handle = ctx
+            .link()
+            .context::<SidebarRegistry>(ctx.link().callback(|_| ()))
+            .map(|(_, handle)| handle);
+
+        Self {
+            _ctx_handle,
+            _registry_handle,
+        }
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
-        let (sidebar_context, _) = ctx
-            .link()
-            .context::<SidebarContext>(Callback::noop())
-            .expect("No sidebar context provided");
-
         let Self::Properties {
             children,
+            target,
+            as_child,
+            label,
             class,
             style,
-            ..
         } = ctx.props();
 
-        let on_click = { Callback::from(move |_| sidebar_context.dispatch(SidebarAction::Toggle)) };
+        let sidebar_context =
+            find_sidebar_context(ctx, target.as_ref()).expect("No sidebar context provided");
+        let expanded = sidebar_context.state.is_expanded();
+        let controls = sidebar_context.element_id.clone();
+
+        let on_click = Callback::from(move |_| sidebar_context.dispatch(SidebarAction::Toggle));
+
+        if *as_child {
+            return with_toggle_attributes(children, expanded, &controls, &on_click);
+        }
 
         html! {
-            <button class={class.clone()} {style} onclick={on_click}>{ children.clone() }</button>
+            <button
+                type="button"
+                class={class.clone()}
+                {style}
+                aria-label={label.clone()}
+                aria-expanded={expanded.to_string()}
+                aria-controls={controls}
+                onclick={on_click}
+            >
+                { children.clone() }
+            </button>
         }
     }
 }
@@ -53,9 +140,34 @@ mod tests {
     #[test]
     fn html_with_all_props() {
         let _ = html! {
-            <SidebarToggle class={classes!("test-class")} style="background-color: red">
-                { "Toggle" }
+            <SidebarToggle
+                target="nav"
+                as_child=true
+                label="Toggle navigation"
+                class={classes!("test-class")}
+                style="background-color: red"
+            >
+                <button>{ "Toggle" }</button>
             </SidebarToggle>
         };
     }
+
+    #[test]
+    fn child_toggle_attributes() {
+        let children = Children::new(vec![html! { <button>{ "Toggle" }</button> }]);
+
+        let VNode::VList(list) =
+            with_toggle_attributes(&children, true, &"sidebar-nav".into(), &Callback::noop())
+        else {
+            panic!("expected a list");
+        };
+        let Some(VNode::VTag(tag)) = list.first() else {
+            panic!("expected an element");
+        };
+
+        assert_eq!(
+            tag.attributes.iter().collect::<Vec<_>>(),
+            vec![("aria-expanded", "true"), ("aria-controls", "sidebar-nav")]
+        );
+    }
 }
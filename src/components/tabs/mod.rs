@@ -1,3 +1,5 @@
+use std::marker::PhantomData;
+
 use yew::prelude::*;
 
 mod tab;
@@ -12,19 +14,19 @@ use crate::contexts::TabsProvider;
 
 /// Properties for the [`Tabs`].
 #[derive(Debug, PartialEq, Properties)]
-pub struct TabsProperties {
+pub struct TabsProperties<T: Clone + PartialEq + 'static = AttrValue> {
     #[prop_or_default]
     pub children: Children,
     /// The value of the default tab to be selected.
     #[prop_or_default]
-    pub default_value: Option<AttrValue>,
+    pub default_value: Option<T>,
     #[prop_or_default]
     pub class: Classes,
     #[prop_or_default]
     pub style: Option<AttrValue>,
     /// A callback function that is called when the selected tab changes.
     #[prop_or_default]
-    pub on_change: Callback<AttrValue>,
+    pub on_change: Callback<T>,
 }
 
 /// A component to display tabs.
@@ -51,15 +53,34 @@ pub struct TabsProperties {
 ///     </Tabs>
 /// }
 /// ```
+///
+/// The values default to [`AttrValue`], any other `Clone + PartialEq` type can be used instead,
+/// as long as [`TabList`], [`Tab`] and [`TabPanel`] use the same type:
+/// ```ignore
+/// html! {
+///     <Tabs<Flower> on_change={Callback::from(|flower: Flower| log::info!("Tab changed to: {flower:?}"))}>
+///         <TabList<Flower>>
+///             <Tab<Flower> value={Flower::Rose}>{"Rose"}</Tab<Flower>>
+///         </TabList<Flower>>
+///         <TabPanel<Flower> value={Flower::Rose}>
+///             <div>{ "Rose" }</div>
+///         </TabPanel<Flower>>
+///     </Tabs<Flower>>
+/// }
+/// ```
 #[derive(Debug)]
-pub struct Tabs;
+pub struct Tabs<T: Clone + PartialEq + 'static = AttrValue> {
+    _marker: PhantomData<T>,
+}
 
-impl Component for Tabs {
+impl<T: Clone + PartialEq + 'static> Component for Tabs<T> {
     type Message = ();
-    type Properties = TabsProperties;
+    type Properties = TabsProperties<T>;
 
     fn create(_ctx: &Context<Self>) -> Self {
-        Self
+        Self {
+            _marker: PhantomData,
+        }
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
@@ -73,9 +94,9 @@ impl Component for Tabs {
         } = ctx.props();
 
         html! {
-            <TabsProvider {default_value} {on_change}>
+            <TabsProvider<T> default_value={default_value.clone()} {on_change}>
                 <div class={classes!("tabs", class.clone())} {style}>{ children.clone() }</div>
-            </TabsProvider>
+            </TabsProvider<T>>
         }
     }
 }
@@ -84,6 +105,12 @@ impl Component for Tabs {
 mod tests {
     use super::*;
 
+    #[derive(Clone, Debug, PartialEq)]
+    enum Flower {
+        Dandelion,
+        Heather,
+    }
+
     #[test]
     fn render_tabs() {
         let _ = html! {
@@ -101,4 +128,25 @@ mod tests {
             </Tabs>
         };
     }
+
+    #[test]
+    fn render_tabs_with_typed_values() {
+        let _ = html! {
+            <Tabs<Flower>
+                default_value={Flower::Heather}
+                on_change={Callback::from(|flower: Flower| log::info!("Tab changed to: {flower:?}"))}
+            >
+                <TabList<Flower>>
+                    <Tab<Flower> value={Flower::Dandelion}>{ "Dandelion" }</Tab<Flower>>
+                    <Tab<Flower> value={Flower::Heather}>{ "Heather" }</Tab<Flower>>
+                </TabList<Flower>>
+                <TabPanel<Flower> value={Flower::Dandelion}>
+                    <div>{ "Dandelion" }</div>
+                </TabPanel<Flower>>
+                <TabPanel<Flower> value={Flower::Heather}>
+                    <div>{ "Heather" }</div>
+                </TabPanel<Flower>>
+            </Tabs<Flower>>
+        };
+    }
 }
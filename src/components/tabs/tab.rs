@@ -1,11 +1,13 @@
+use std::marker::PhantomData;
+
 use yew::prelude::*;
 
 use crate::contexts::{TabsAction, TabsContext};
 
 /// Properties for the [`Tab`].
 #[derive(Clone, Debug, PartialEq, Properties)]
-pub struct TabProperties {
-    pub value: AttrValue,
+pub struct TabProperties<T: Clone + PartialEq + 'static = AttrValue> {
+    pub value: T,
     #[prop_or_default]
     pub children: Children,
     #[prop_or_default]
@@ -22,20 +24,24 @@ pub struct TabProperties {
 
 /// A component to represent a single tab in a [`TabList`] component.
 #[derive(Debug)]
-pub struct Tab;
+pub struct Tab<T: Clone + PartialEq + 'static = AttrValue> {
+    _marker: PhantomData<T>,
+}
 
-impl Component for Tab {
+impl<T: Clone + PartialEq + 'static> Component for Tab<T> {
     type Message = ();
-    type Properties = TabProperties;
+    type Properties = TabProperties<T>;
 
     fn create(_ctx: &Context<Self>) -> Self {
-        Self
+        Self {
+            _marker: PhantomData,
+        }
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
         let (tabs_context, _) = ctx
             .link()
-            .context::<TabsContext>(Callback::noop())
+            .context::<TabsContext<T>>(Callback::noop())
             .expect("No tabs context provided");
 
         let Self::Properties {
@@ -1,5 +1,5 @@
 use gloo::timers::callback::Timeout;
-use std::{collections::HashMap, rc::Rc};
+use std::rc::Rc;
 use web_sys::HtmlElement;
 use yew::prelude::*;
 
@@ -9,9 +9,9 @@ use super::Tab;
 
 /// Properties for the [`TabList`].
 #[derive(Debug, PartialEq, Properties)]
-pub struct TabListProperties {
+pub struct TabListProperties<T: Clone + PartialEq + 'static = AttrValue> {
     #[prop_or_default]
-    pub children: ChildrenWithProps<Tab>,
+    pub children: ChildrenWithProps<Tab<T>>,
     #[prop_or_default]
     pub class: Classes,
     #[prop_or_default]
@@ -20,50 +20,46 @@ pub struct TabListProperties {
 
 /// A component to contain a list of [`Tab`].
 #[derive(Debug)]
-pub struct TabList {
+pub struct TabList<T: Clone + PartialEq + 'static = AttrValue> {
     indicator_ref: NodeRef,
-    tab_refs: HashMap<String, NodeRef>,
-    _ctx_handle: ContextHandle<TabsContext>,
+    tab_refs: Vec<(T, NodeRef)>,
+    _ctx_handle: ContextHandle<TabsContext<T>>,
 }
 
-impl Component for TabList {
+impl<T: Clone + PartialEq + 'static> Component for TabList<T> {
     type Message = ();
-    type Properties = TabListProperties;
+    type Properties = TabListProperties<T>;
 
     fn create(ctx: &Context<Self>) -> Self {
         let (tabs_context, _ctx_handle) = ctx
             .link()
-            .context::<TabsContext>(ctx.link().callback(|_| ()))
+            .context::<TabsContext<T>>(ctx.link().callback(|_| ()))
             .expect("No tabs context provided");
 
-        let tab_refs = ctx
-            .props()
-            .children
-            .iter()
-            .enumerate()
-            .map(|(index, child)| {
-                let value = child.props.value.clone();
-                if index == 0 && tabs_context.state.selected_tab.is_empty() {
-                    tabs_context
-                        .state
-                        .dispatch(TabsAction::Select(value.clone()));
-                }
-
-                (value.to_string(), NodeRef::default())
-            })
-            .collect::<HashMap<_, _>>();
+        if let Some(first) = ctx.props().children.iter().next()
+            && tabs_context.state.selected_tab.is_none()
+        {
+            tabs_context
+                .state
+                .dispatch(TabsAction::Select(first.props.value.clone()));
+        }
 
         Self {
             indicator_ref: NodeRef::default(),
-            tab_refs,
+            tab_refs: tab_refs(&ctx.props().children, Vec::new()),
             _ctx_handle,
         }
     }
 
+    fn changed(&mut self, ctx: &Context<Self>, _old_props: &Self::Properties) -> bool {
+        self.tab_refs = tab_refs(&ctx.props().children, std::mem::take(&mut self.tab_refs));
+        true
+    }
+
     fn view(&self, ctx: &Context<Self>) -> Html {
         let (tabs_context, _) = ctx
             .link()
-            .context::<TabsContext>(Callback::noop())
+            .context::<TabsContext<T>>(Callback::noop())
             .expect("No tabs context provided");
 
         let TabListProperties {
@@ -73,16 +69,16 @@ impl Component for TabList {
             ..
         } = ctx.props();
 
-        let selected_tab = &tabs_context.state.selected_tab;
+        let selected_tab = tabs_context.state.selected_tab.as_ref();
 
         let children = children
             .iter()
-            .map(|mut child| {
+            .zip(self.tab_refs.iter())
+            .map(|(mut child, (_, tab_ref))| {
                 let props = Rc::make_mut(&mut child.props);
-                let value = props.value.clone();
 
-                props.is_selected = &value == selected_tab;
-                props.node_ref = self.tab_refs[&value.to_string()].clone();
+                props.is_selected = selected_tab == Some(&props.value);
+                props.node_ref = tab_ref.clone();
 
                 child
             })
@@ -99,7 +95,7 @@ impl Component for TabList {
     fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {
         let (tabs_context, _) = ctx
             .link()
-            .context::<TabsContext>(Callback::noop())
+            .context::<TabsContext<T>>(Callback::noop())
             .expect("No tabs context provided");
 
         let selected = tabs_context.state.selected_tab.clone();
@@ -108,37 +104,69 @@ impl Component for TabList {
             let tab_refs = self.tab_refs.clone();
             let indicator_ref = self.indicator_ref.clone();
             let _ = Timeout::new(0, move || {
-                update_indicator_position(&tab_refs, &indicator_ref, &selected, first_render);
+                update_indicator_position(
+                    &tab_refs,
+                    &indicator_ref,
+                    selected.as_ref(),
+                    first_render,
+                );
             })
             .forget();
         } else {
-            update_indicator_position(&self.tab_refs, &self.indicator_ref, &selected, first_render);
+            update_indicator_position(
+                &self.tab_refs,
+                &self.indicator_ref,
+                selected.as_ref(),
+                first_render,
+            );
         }
     }
 }
 
-fn update_indicator_position(
-    tab_refs: &HashMap<String, NodeRef>,
+/// Returns a ref for each tab, in order, keeping the refs of the tabs which were already there.
+fn tab_refs<T: Clone + PartialEq + 'static>(
+    children: &ChildrenWithProps<Tab<T>>,
+    mut previous: Vec<(T, NodeRef)>,
+) -> Vec<(T, NodeRef)> {
+    children
+        .iter()
+        .map(|child| {
+            let value = child.props.value.clone();
+            let node_ref = match previous.iter().position(|(v, _)| *v == value) {
+                Some(index) => previous.remove(index).1,
+                None => NodeRef::default(),
+            };
+
+            (value, node_ref)
+        })
+        .collect()
+}
+
+fn update_indicator_position<T: PartialEq>(
+    tab_refs: &[(T, NodeRef)],
     indicator_ref: &NodeRef,
-    selected: &str,
+    selected: Option<&T>,
     first_render: bool,
 ) {
-    if let Some(tab_ref) = tab_refs.get(&selected.to_string()) {
-        if let Some(tab) = tab_ref.cast::<HtmlElement>() {
-            let indicator_style = format!(
-                "width: {}px; transform: translateX({}px);{}",
-                tab.client_width(),
-                tab.offset_left(),
-                if first_render {
-                    " transition: none;"
-                } else {
-                    ""
-                }
-            );
-
-            if let Some(indicator) = indicator_ref.cast::<HtmlElement>() {
-                let _ = indicator.set_attribute("style", &indicator_style);
+    let tab = tab_refs
+        .iter()
+        .find(|(value, _)| Some(value) == selected)
+        .and_then(|(_, tab_ref)| tab_ref.cast::<HtmlElement>());
+
+    if let Some(tab) = tab {
+        let indicator_style = format!(
+            "width: {}px; transform: translateX({}px);{}",
+            tab.client_width(),
+            tab.offset_left(),
+            if first_render {
+                " transition: none;"
+            } else {
+                ""
             }
+        );
+
+        if let Some(indicator) = indicator_ref.cast::<HtmlElement>() {
+            let _ = indicator.set_attribute("style", &indicator_style);
         }
     }
 }
@@ -147,6 +175,26 @@ fn update_indicator_position(
 mod tests {
     use super::*;
 
+    #[test]
+    fn tab_refs_follow_children() {
+        let children = |values: &[&'static str]| {
+            ChildrenWithProps::<Tab>::new(
+                values
+                    .iter()
+                    .map(|value| html_nested! { <Tab value={*value} /> })
+                    .collect(),
+            )
+        };
+
+        let refs = tab_refs(&children(&["1", "2"]), Vec::new());
+        let refs_2 = refs[1].1.clone();
+        let refs = tab_refs(&children(&["3", "2", "1"]), refs);
+
+        let values: Vec<_> = refs.iter().map(|(value, _)| value.as_str()).collect();
+        assert_eq!(values, ["3", "2", "1"]);
+        assert_eq!(refs[1].1, refs_2, "the ref of a moved tab is kept");
+    }
+
     #[test]
     fn render_tab_list() {
         let _ = html! {
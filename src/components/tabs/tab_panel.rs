@@ -4,8 +4,8 @@ use crate::contexts::TabsContext;
 
 /// Properties for the [`TabPanel`].
 #[derive(Debug, PartialEq, Properties)]
-pub struct TabPanelProperties {
-    pub value: AttrValue,
+pub struct TabPanelProperties<T: Clone + PartialEq + 'static = AttrValue> {
+    pub value: T,
     #[prop_or_default]
     pub children: Children,
     #[prop_or_default]
@@ -25,18 +25,18 @@ pub struct TabPanelProperties {
 /// }
 /// ```
 #[derive(Debug)]
-pub struct TabPanel {
-    _ctx_handle: ContextHandle<TabsContext>,
+pub struct TabPanel<T: Clone + PartialEq + 'static = AttrValue> {
+    _ctx_handle: ContextHandle<TabsContext<T>>,
 }
 
-impl Component for TabPanel {
+impl<T: Clone + PartialEq + 'static> Component for TabPanel<T> {
     type Message = ();
-    type Properties = TabPanelProperties;
+    type Properties = TabPanelProperties<T>;
 
     fn create(ctx: &Context<Self>) -> Self {
         let (_tabs_context, _ctx_handle) = ctx
             .link()
-            .context::<TabsContext>(ctx.link().callback(|_| ()))
+            .context::<TabsContext<T>>(ctx.link().callback(|_| ()))
             .expect("No tabs context provided");
 
         Self { _ctx_handle }
@@ -45,7 +45,7 @@ impl Component for TabPanel {
     fn view(&self, ctx: &Context<Self>) -> Html {
         let (tabs_context, _) = ctx
             .link()
-            .context::<TabsContext>(Callback::noop())
+            .context::<TabsContext<T>>(Callback::noop())
             .expect("No tabs context provided");
 
         let TabPanelProperties {
@@ -56,7 +56,7 @@ impl Component for TabPanel {
             ..
         } = ctx.props();
 
-        let is_selected = value.clone() == tabs_context.state.selected_tab;
+        let is_selected = tabs_context.state.selected_tab.as_ref() == Some(value);
 
         html! {
             <div
@@ -0,0 +1,124 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use yew::prelude::*;
+
+static NEXT_DIALOG_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// A part of a dialog referenced by its ARIA attributes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DialogPart {
+    /// Labels the dialog, e.g. [`DialogTitle`](crate::components::DialogTitle).
+    Title,
+    /// Describes the dialog, e.g. [`DialogContent`](crate::components::DialogContent).
+    Description,
+}
+
+/// The ids a [`Dialog`](crate::components::Dialog) is labelled and described by, given to its
+/// [`DialogTitle`](crate::components::DialogTitle) and [`DialogContent`](crate::components::DialogContent).
+///
+/// The parts report when they are mounted, so that the dialog only references existing elements.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DialogContext {
+    pub title_id: AttrValue,
+    pub description_id: AttrValue,
+    /// Called with a part and whether it was mounted or unmounted.
+    pub on_part: Callback<(DialogPart, bool)>,
+}
+
+impl DialogContext {
+    /// Returns the ids of a new dialog, unique within the page.
+    pub fn new(on_part: Callback<(DialogPart, bool)>) -> Self {
+        let id = NEXT_DIALOG_ID.fetch_add(1, Ordering::Relaxed);
+
+        Self {
+            title_id: AttrValue::from(format!("dialog-{id}-title")),
+            description_id: AttrValue::from(format!("dialog-{id}-description")),
+            on_part,
+        }
+    }
+
+    pub fn id(&self, part: DialogPart) -> AttrValue {
+        match part {
+            DialogPart::Title => self.title_id.clone(),
+            DialogPart::Description => self.description_id.clone(),
+        }
+    }
+
+    /// Reports the part as mounted and returns its id.
+    pub fn mount(&self, part: DialogPart) -> AttrValue {
+        self.on_part.emit((part, true));
+
+        self.id(part)
+    }
+
+    pub fn unmount(&self, part: DialogPart) {
+        self.on_part.emit((part, false));
+    }
+}
+
+/// How many of each part of a dialog are mounted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DialogParts {
+    titles: usize,
+    descriptions: usize,
+}
+
+impl DialogParts {
+    /// Counts a part in or out, returns whether the part started or stopped being present.
+    pub fn update(&mut self, part: DialogPart, mounted: bool) -> bool {
+        let count = match part {
+            DialogPart::Title => &mut self.titles,
+            DialogPart::Description => &mut self.descriptions,
+        };
+        let was_present = *count > 0;
+        *count = if mounted {
+            *count + 1
+        } else {
+            count.saturating_sub(1)
+        };
+
+        was_present != (*count > 0)
+    }
+
+    /// Returns the value of `aria-labelledby`, if there is a title.
+    pub fn labelledby(&self, context: &DialogContext) -> Option<AttrValue> {
+        (self.titles > 0).then(|| context.title_id.clone())
+    }
+
+    /// Returns the value of `aria-describedby`, if there is a description.
+    pub fn describedby(&self, context: &DialogContext) -> Option<AttrValue> {
+        (self.descriptions > 0).then(|| context.description_id.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unique_ids() {
+        let first = DialogContext::new(Callback::noop());
+        let second = DialogContext::new(Callback::noop());
+
+        assert_ne!(first.title_id, first.description_id);
+        assert_ne!(first.title_id, second.title_id);
+        assert_ne!(first.description_id, second.description_id);
+    }
+
+    #[test]
+    fn present_parts() {
+        let context = DialogContext::new(Callback::noop());
+        let mut parts = DialogParts::default();
+        assert_eq!(parts.labelledby(&context), None);
+        assert_eq!(parts.describedby(&context), None);
+
+        assert!(parts.update(DialogPart::Title, true));
+        assert!(!parts.update(DialogPart::Title, true), "already present");
+        assert_eq!(parts.labelledby(&context), Some(context.title_id.clone()));
+        assert_eq!(parts.describedby(&context), None);
+
+        assert!(!parts.update(DialogPart::Title, false));
+        assert!(parts.update(DialogPart::Title, false));
+        assert_eq!(parts.labelledby(&context), None);
+    }
+}
@@ -0,0 +1,410 @@
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::future::Future;
+use std::rc::Rc;
+
+use futures::channel::oneshot;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+use crate::components::{
+    Dialog, DialogContent, DialogFooter, DialogHeader, DialogSize, DialogTitle,
+};
+
+/// The content of a dialog opened through [`use_dialog`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct DialogOptions {
+    pub title: AttrValue,
+    pub message: AttrValue,
+    pub confirm_label: AttrValue,
+    /// Not shown by alerts.
+    pub cancel_label: AttrValue,
+    /// The initial value of the input of prompts.
+    pub default_value: AttrValue,
+}
+
+impl Default for DialogOptions {
+    fn default() -> Self {
+        Self {
+            title: AttrValue::default(),
+            message: AttrValue::default(),
+            confirm_label: AttrValue::from("OK"),
+            cancel_label: AttrValue::from("Cancel"),
+            default_value: AttrValue::default(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DialogKind {
+    Alert,
+    Confirm,
+    Prompt,
+}
+
+/// A dialog waiting for an answer, answered with `Some` when confirmed and `None` when cancelled.
+#[derive(Debug)]
+pub struct DialogRequest {
+    pub id: usize,
+    pub kind: DialogKind,
+    pub options: DialogOptions,
+    responder: RefCell<Option<oneshot::Sender<Option<String>>>>,
+}
+
+impl DialogRequest {
+    fn respond(&self, response: Option<String>) {
+        if let Some(responder) = self.responder.borrow_mut().take() {
+            // The caller may have stopped waiting for the answer.
+            let _ = responder.send(response);
+        }
+    }
+}
+
+/// The dialogs requested through [`use_dialog`], shown one at a time in order.
+#[derive(Debug, Default)]
+pub struct DialogQueue {
+    requests: VecDeque<Rc<DialogRequest>>,
+    /// The last request shown, kept so that its content stays visible while the dialog closes.
+    last_shown: Option<Rc<DialogRequest>>,
+    next_id: usize,
+}
+
+impl DialogQueue {
+    pub fn current(&self) -> Option<&Rc<DialogRequest>> {
+        self.requests.front().or(self.last_shown.as_ref())
+    }
+
+    pub fn is_open(&self) -> bool {
+        !self.requests.is_empty()
+    }
+}
+
+#[derive(Debug)]
+pub enum DialogQueueAction {
+    Push {
+        kind: DialogKind,
+        options: DialogOptions,
+        responder: oneshot::Sender<Option<String>>,
+    },
+    /// Removes the answered request, ignored unless it is the current one, e.g. when answered twice.
+    Respond { id: usize },
+}
+
+impl Reducible for DialogQueue {
+    type Action = DialogQueueAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut requests = self.requests.clone();
+        let mut last_shown = self.last_shown.clone();
+        let mut next_id = self.next_id;
+
+        match action {
+            DialogQueueAction::Push {
+                kind,
+                options,
+                responder,
+            } => {
+                requests.push_back(Rc::new(DialogRequest {
+                    id: next_id,
+                    kind,
+                    options,
+                    responder: RefCell::new(Some(responder)),
+                }));
+                next_id += 1;
+            }
+            DialogQueueAction::Respond { id } => {
+                if requests.front().is_none_or(|request| request.id != id) {
+                    return self;
+                }
+                last_shown = requests.pop_front();
+            }
+        }
+
+        Rc::new(Self {
+            requests,
+            last_shown,
+            next_id,
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DialogsContext {
+    pub dispatcher: UseReducerDispatcher<DialogQueue>,
+}
+
+/// A handle to open dialogs imperatively, returned by [`use_dialog`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct UseDialogHandle {
+    dispatcher: UseReducerDispatcher<DialogQueue>,
+}
+
+impl UseDialogHandle {
+    fn request(
+        &self,
+        kind: DialogKind,
+        options: DialogOptions,
+    ) -> oneshot::Receiver<Option<String>> {
+        let (responder, response) = oneshot::channel();
+        self.dispatcher.dispatch(DialogQueueAction::Push {
+            kind,
+            options,
+            responder,
+        });
+
+        response
+    }
+
+    /// Shows a message, resolves once it is dismissed.
+    pub fn alert(&self, options: DialogOptions) -> impl Future<Output = ()> + use<> {
+        let response = self.request(DialogKind::Alert, options);
+
+        async move {
+            let _ = response.await;
+        }
+    }
+
+    /// Asks for a confirmation, resolves to `true` if confirmed.
+    pub fn confirm(&self, options: DialogOptions) -> impl Future<Output = bool> + use<> {
+        let response = self.request(DialogKind::Confirm, options);
+
+        async move { response.await.ok().flatten().is_some() }
+    }
+
+    /// Asks for a text, resolves to `None` if cancelled.
+    pub fn prompt(&self, options: DialogOptions) -> impl Future<Output = Option<String>> + use<> {
+        let response = self.request(DialogKind::Prompt, options);
+
+        async move { response.await.ok().flatten() }
+    }
+}
+
+/// Returns a handle to open alert, confirm and prompt dialogs from callbacks.
+///
+/// Usage:
+/// ```ignore
+/// let dialog = use_dialog();
+///
+/// let on_delete = Callback::from(move |_| {
+///     let dialog = dialog.clone();
+///     yew::platform::spawn_local(async move {
+///         let options = DialogOptions {
+///             title: "Delete the file?".into(),
+///             confirm_label: "Delete".into(),
+///             ..Default::default()
+///         };
+///         if dialog.confirm(options).await {
+///             log::info!("Deleted");
+///         }
+///     });
+/// });
+/// ```
+#[hook]
+pub fn use_dialog() -> UseDialogHandle {
+    let context = use_context::<DialogsContext>().expect("No dialogs context provided");
+
+    UseDialogHandle {
+        dispatcher: context.dispatcher,
+    }
+}
+
+#[derive(Debug, PartialEq, Properties)]
+pub struct DialogProviderProperties {
+    #[prop_or_default]
+    pub children: Children,
+}
+
+/// Renders the dialogs requested through [`use_dialog`] with [`Dialog`].
+#[function_component]
+pub fn DialogProvider(props: &DialogProviderProperties) -> Html {
+    let queue = use_reducer(DialogQueue::default);
+    let input_ref = use_node_ref();
+    let confirm_ref = use_node_ref();
+    let cancel_ref = use_node_ref();
+
+    let context = DialogsContext {
+        dispatcher: queue.dispatcher(),
+    };
+
+    // Answers the request shown by this render, so that a second answer to it is ignored.
+    let respond = {
+        let dispatcher = queue.dispatcher();
+        let request = queue.requests.front().cloned();
+        Callback::from(move |response: Option<String>| {
+            if let Some(request) = &request {
+                request.respond(response);
+                dispatcher.dispatch(DialogQueueAction::Respond { id: request.id });
+            }
+        })
+    };
+
+    let on_confirm = {
+        let respond = respond.clone();
+        let input_ref = input_ref.clone();
+        Callback::from(move |_: ()| {
+            let value = input_ref
+                .cast::<HtmlInputElement>()
+                .map(|input| input.value())
+                .unwrap_or_default();
+            respond.emit(Some(value));
+        })
+    };
+
+    let on_cancel = respond.reform(|_: ()| None);
+
+    let content = queue.current().map(|request| {
+        let DialogOptions {
+            title,
+            message,
+            confirm_label,
+            cancel_label,
+            default_value,
+        } = &request.options;
+
+        let input = (request.kind == DialogKind::Prompt).then(|| {
+            let on_confirm = on_confirm.clone();
+            html! {
+                <input
+                    key={request.id}
+                    ref={input_ref.clone()}
+                    value={default_value.clone()}
+                    onkeydown={Callback::from(move |e: KeyboardEvent| {
+                        if e.key() == "Enter" {
+                            on_confirm.emit(());
+                        }
+                    })}
+                />
+            }
+        });
+
+        let cancel = (request.kind != DialogKind::Alert).then(|| {
+            html! {
+                <button ref={cancel_ref.clone()} onclick={on_cancel.reform(|_| ())}>
+                    { cancel_label.clone() }
+                </button>
+            }
+        });
+
+        html! {
+            <>
+                <DialogHeader>
+                    <DialogTitle>{ title.clone() }</DialogTitle>
+                </DialogHeader>
+                <DialogContent>
+                    <div>{ message.clone() }</div>
+                    { input }
+                </DialogContent>
+                <DialogFooter>
+                    { cancel }
+                    <button ref={confirm_ref.clone()} onclick={on_confirm.reform(|_| ())}>
+                        { confirm_label.clone() }
+                    </button>
+                </DialogFooter>
+            </>
+        }
+    });
+
+    // Confirmations get the focus on the least destructive action.
+    let (role, initial_focus) = match queue.current().map(|request| request.kind) {
+        Some(DialogKind::Prompt) => ("dialog", input_ref.clone()),
+        Some(DialogKind::Confirm) => ("alertdialog", cancel_ref.clone()),
+        _ => ("alertdialog", confirm_ref.clone()),
+    };
+    // Mounted again for each request, so that the `initial_focus` of the next queued one is focused.
+    let key = queue
+        .current()
+        .map(|request| request.id)
+        .unwrap_or_default();
+
+    html! {
+        <ContextProvider<DialogsContext> {context}>
+            { props.children.clone() }
+            <Dialog
+                {key}
+                open={queue.is_open()}
+                size={DialogSize::Sm}
+                on_close={on_cancel.reform(|_| ())}
+                {role}
+                {initial_focus}
+            >
+                { content.unwrap_or_default() }
+            </Dialog>
+        </ContextProvider<DialogsContext>>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn html_with_all_props() {
+        let _ = html! {
+            <DialogProvider>
+                <div>{ "App" }</div>
+            </DialogProvider>
+        };
+    }
+
+    #[test]
+    fn dialog_queue_reducer() {
+        let (first, mut first_response) = oneshot::channel();
+        let (second, mut second_response) = oneshot::channel();
+
+        let queue = Rc::new(DialogQueue::default());
+        let queue = DialogQueue::reduce(
+            queue,
+            DialogQueueAction::Push {
+                kind: DialogKind::Confirm,
+                options: DialogOptions::default(),
+                responder: first,
+            },
+        );
+        let queue = DialogQueue::reduce(
+            queue,
+            DialogQueueAction::Push {
+                kind: DialogKind::Prompt,
+                options: DialogOptions::default(),
+                responder: second,
+            },
+        );
+
+        assert!(queue.is_open());
+        assert_eq!(
+            queue.current().map(|request| request.kind),
+            Some(DialogKind::Confirm)
+        );
+
+        let first_id = queue.current().map_or(0, |request| request.id);
+        queue.current().unwrap().respond(None);
+        let queue = DialogQueue::reduce(queue, DialogQueueAction::Respond { id: first_id });
+        assert_eq!(first_response.try_recv(), Ok(Some(None)));
+        assert_eq!(
+            queue.current().map(|request| request.kind),
+            Some(DialogKind::Prompt)
+        );
+
+        // Answered twice, e.g. by a double click.
+        let queue = DialogQueue::reduce(queue, DialogQueueAction::Respond { id: first_id });
+        assert_eq!(
+            queue.current().map(|request| request.kind),
+            Some(DialogKind::Prompt),
+            "a stale answer leaves the next request open"
+        );
+        assert_eq!(second_response.try_recv(), Ok(None));
+
+        let second_id = queue.current().map_or(0, |request| request.id);
+        queue.current().unwrap().respond(Some("name".into()));
+        let queue = DialogQueue::reduce(queue, DialogQueueAction::Respond { id: second_id });
+        assert_eq!(
+            second_response.try_recv(),
+            Ok(Some(Some("name".to_string())))
+        );
+        assert!(!queue.is_open());
+        // Kept to be shown while the dialog closes.
+        assert_eq!(
+            queue.current().map(|request| request.kind),
+            Some(DialogKind::Prompt)
+        );
+    }
+}
@@ -0,0 +1,17 @@
+use yew::prelude::*;
+
+/// Reports errors to the closest [`ErrorBoundary`](crate::components::ErrorBoundary), which shows
+/// them in place of its content.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ErrorBoundaryContext {
+    pub report: Callback<String>,
+}
+
+/// Returns a callback showing an error in place of the content of the closest
+/// [`ErrorBoundary`](crate::components::ErrorBoundary), e.g. when a page failed to load its data.
+#[hook]
+pub fn use_error_boundary() -> Callback<String> {
+    use_context::<ErrorBoundaryContext>()
+        .expect("No error boundary provided")
+        .report
+}
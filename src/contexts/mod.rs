@@ -1,5 +1,13 @@
+pub mod dialog_context;
+pub mod dialogs_context;
+pub mod error_boundary_context;
+pub mod portal_context;
 pub mod sidebar_context;
 pub mod tabs_context;
 
+pub use dialog_context::*;
+pub use dialogs_context::*;
+pub use error_boundary_context::*;
+pub use portal_context::*;
 pub use sidebar_context::*;
 pub use tabs_context::*;
@@ -0,0 +1,100 @@
+use web_sys::Element;
+use yew::prelude::*;
+
+const DEFAULT_CONTAINER_ID: &str = "modal-root";
+const DEFAULT_INERT_TARGET_ID: &str = "app-root";
+
+/// Where portals (e.g. [`Modal`](crate::components::Modal)) are rendered, and what is made `inert`
+/// behind them.
+///
+/// Components fall back to [`PortalContext::default`] when no [`PortalProvider`] is found.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PortalContext {
+    pub container_id: AttrValue,
+    pub inert_target_id: AttrValue,
+}
+
+impl Default for PortalContext {
+    fn default() -> Self {
+        Self {
+            container_id: AttrValue::from(DEFAULT_CONTAINER_ID),
+            inert_target_id: AttrValue::from(DEFAULT_INERT_TARGET_ID),
+        }
+    }
+}
+
+impl PortalContext {
+    /// Returns the element to render portals into, it is created at the end of `<body>` if absent.
+    pub fn container(&self) -> Option<Element> {
+        let document = gloo::utils::document();
+
+        if let Some(container) = document.get_element_by_id(&self.container_id) {
+            return Some(container);
+        }
+
+        let container = document.create_element("div").ok()?;
+        container.set_id(&self.container_id);
+        document.body()?.append_child(&container).ok()?;
+
+        Some(container)
+    }
+
+    /// Returns the element to make `inert` while a modal is open, if it exists.
+    pub fn inert_target(&self) -> Option<Element> {
+        gloo::utils::document().get_element_by_id(&self.inert_target_id)
+    }
+}
+
+#[derive(Debug, PartialEq, Properties)]
+pub struct PortalProviderProperties {
+    #[prop_or_default]
+    pub children: Children,
+    /// The id of the element to render portals into, created at the end of `<body>` if absent.
+    #[prop_or(AttrValue::from(DEFAULT_CONTAINER_ID))]
+    pub container_id: AttrValue,
+    /// The id of the element to make `inert` while a modal is open, nothing is made inert if absent.
+    #[prop_or(AttrValue::from(DEFAULT_INERT_TARGET_ID))]
+    pub inert_target_id: AttrValue,
+}
+
+#[function_component]
+pub fn PortalProvider(props: &PortalProviderProperties) -> Html {
+    let PortalProviderProperties {
+        children,
+        container_id,
+        inert_target_id,
+    } = props;
+
+    let context = PortalContext {
+        container_id: container_id.clone(),
+        inert_target_id: inert_target_id.clone(),
+    };
+
+    html! {
+        <ContextProvider<PortalContext> {context}>
+            { children.clone() }
+        </ContextProvider<PortalContext>>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn html_with_all_props() {
+        let _ = html! {
+            <PortalProvider container_id="widget-portals" inert_target_id="widget-root">
+                <div>{ "Widget" }</div>
+            </PortalProvider>
+        };
+    }
+
+    #[test]
+    fn default_ids() {
+        let context = PortalContext::default();
+
+        assert_eq!(context.container_id, DEFAULT_CONTAINER_ID);
+        assert_eq!(context.inert_target_id, DEFAULT_INERT_TARGET_ID);
+    }
+}
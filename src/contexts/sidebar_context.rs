@@ -1,67 +1,634 @@
+use std::collections::BTreeSet;
 use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
+use gloo::events::{EventListener, EventListenerOptions};
+use gloo::storage::{LocalStorage, Storage};
+use web_sys::wasm_bindgen::JsCast;
+use web_sys::{Element, MediaQueryList};
 use yew::prelude::*;
+use yew_router::prelude::use_location;
 
-const SIDEBAR_KEYBOARD_SHORTCUT: &str = "b";
+use crate::components::any_modal_open;
+use crate::utils::KeyCombo;
 
+const SIDEBAR_KEYBOARD_SHORTCUT: &str = "mod+b";
+
+static NEXT_SIDEBAR_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Returns the id of the element of a new sidebar without id, unique within the page. Prefixed
+/// differently from the ids of [`element_id`], so that they can't collide.
+fn next_element_id() -> AttrValue {
+    let id = NEXT_SIDEBAR_ID.fetch_add(1, Ordering::Relaxed);
+
+    AttrValue::from(format!("sidebar-auto-{id}"))
+}
+
+/// Returns the id of the element of the sidebar with the id.
+fn element_id(id: &str) -> AttrValue {
+    AttrValue::from(format!("sidebar-{id}"))
+}
+
+/// Elements typing into which doesn't trigger the shortcut, if asked to.
+const TEXT_FIELD_SELECTOR: &str =
+    "input, textarea, select, [contenteditable]:not([contenteditable=\"false\"])";
+
+fn default_shortcut() -> Option<KeyCombo> {
+    SIDEBAR_KEYBOARD_SHORTCUT.parse().ok()
+}
+
+/// The `localStorage` key the expanded groups are persisted under, next to the open state.
+fn groups_storage_key(storage_key: &str) -> String {
+    format!("{storage_key}-groups")
+}
+
+/// The `localStorage` key the width is persisted under, next to the open state.
+fn width_storage_key(storage_key: &str) -> String {
+    format!("{storage_key}-width")
+}
+
+/// The state last written to the `localStorage`.
 #[derive(Clone, Debug, PartialEq)]
-pub struct Sidebar {
-    pub is_open: bool,
+struct Persisted {
+    key: Option<AttrValue>,
+    /// `None` while the open state is controlled.
+    open: Option<bool>,
+    expanded_groups: BTreeSet<String>,
+    width: Option<f64>,
+}
+
+/// Reduces `action` against the live state, and returns the open state to report if it changes.
+///
+/// A controlled open state is only reported: it stays the one of the `open` prop until the parent
+/// renders with a new one, so that a rejected change is asked again by the next toggle.
+fn reduce_live(
+    live: &mut SidebarState,
+    action: SidebarAction,
+    is_controlled: bool,
+) -> Option<bool> {
+    let was_open = live.open;
+    let mut next = (*Rc::new(live.clone()).reduce(action)).clone();
+    let open_change = (next.open != was_open).then_some(next.open);
+    if is_controlled {
+        next.open = was_open;
+    }
+    *live = next;
+
+    open_change
+}
+
+fn is_text_field(target: Option<web_sys::EventTarget>) -> bool {
+    target
+        .and_then(|target| target.dyn_into::<Element>().ok())
+        .and_then(|element| element.closest(TEXT_FIELD_SELECTOR).ok().flatten())
+        .is_some()
+}
+
+/// The edge of the page the sidebar is on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SidebarSide {
+    #[default]
+    Left,
+    Right,
+}
+
+impl SidebarSide {
+    pub fn class(self) -> &'static str {
+        match self {
+            Self::Left => "sidebar-left",
+            Self::Right => "sidebar-right",
+        }
+    }
+}
+
+/// How the sidebar is laid out next to the page.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SidebarVariant {
+    /// Along the edge of the page.
+    #[default]
+    Sidebar,
+    /// Detached from the edge, with rounded corners and a shadow.
+    Floating,
+    /// Blended with the page, without a background of its own.
+    Inset,
+}
+
+impl SidebarVariant {
+    pub fn class(self) -> &'static str {
+        match self {
+            Self::Sidebar => "sidebar-default",
+            Self::Floating => "sidebar-floating",
+            Self::Inset => "sidebar-inset",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SidebarState {
+    /// Whether the sidebar is open on desktop.
+    pub open: bool,
+    /// Whether the sidebar is open on mobile, where it is shown over the page.
+    pub open_mobile: bool,
+    /// Whether the viewport is narrower than the mobile breakpoint.
+    pub is_mobile: bool,
+    /// The ids of the expanded [`SidebarGroup`](crate::components::SidebarGroup)s.
+    pub expanded_groups: BTreeSet<String>,
+    /// The width in pixels the sidebar was resized to, the one of the stylesheet if `None`.
+    pub width: Option<f64>,
+    /// Whether the sidebar is being resized, until which its width isn't persisted.
+    pub resizing: bool,
+    pub side: SidebarSide,
+    pub variant: SidebarVariant,
+}
+
+impl SidebarState {
+    /// Whether the sidebar is open in the current mode.
+    pub fn is_expanded(&self) -> bool {
+        if self.is_mobile {
+            self.open_mobile
+        } else {
+            self.open
+        }
+    }
+
+    pub fn is_group_expanded(&self, id: &str) -> bool {
+        self.expanded_groups.contains(id)
+    }
 }
 
-#[derive(Debug)]
+/// Changes the [`SidebarState`].
+///
+/// `Open`, `Close`, `Set` and `Toggle` change the open state of the current mode, i.e. the mobile
+/// one under the mobile breakpoint.
+#[derive(Clone, Debug)]
 pub enum SidebarAction {
+    Open,
+    Close,
+    Set(bool),
     Toggle,
+    /// The viewport crossed the mobile breakpoint.
+    SetMobile(bool),
+    /// Expands or collapses the group with the given id.
+    ToggleGroup(AttrValue),
+    /// Expands the group with the given id, e.g. because it contains the current page.
+    ExpandGroup(AttrValue),
+    /// Resizes the expanded sidebar to the given width in pixels.
+    SetWidth(f64),
+    /// Starts or ends resizing the sidebar, the width is persisted once it ends.
+    SetResizing(bool),
+    SetSide(SidebarSide),
+    SetVariant(SidebarVariant),
 }
 
-impl Reducible for Sidebar {
+impl Reducible for SidebarState {
     type Action = SidebarAction;
 
     fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
         match action {
-            Self::Action::Toggle => Rc::new(Self {
-                is_open: !self.is_open,
+            Self::Action::Open => self.reduce(Self::Action::Set(true)),
+            Self::Action::Close => self.reduce(Self::Action::Set(false)),
+            Self::Action::Toggle => {
+                let open = !self.is_expanded();
+                self.reduce(Self::Action::Set(open))
+            }
+            Self::Action::Set(open) if open == self.is_expanded() => self,
+            Self::Action::Set(open_mobile) if self.is_mobile => Rc::new(Self {
+                open_mobile,
+                ..(*self).clone()
+            }),
+            Self::Action::Set(open) => Rc::new(Self {
+                open,
+                ..(*self).clone()
+            }),
+            Self::Action::SetMobile(is_mobile) => Rc::new(Self {
+                is_mobile,
+                // Closed when leaving mobile, so that it doesn't pop over the page when coming back.
+                open_mobile: self.open_mobile && is_mobile,
+                ..(*self).clone()
+            }),
+            Self::Action::ToggleGroup(id) => {
+                let mut expanded_groups = self.expanded_groups.clone();
+                if !expanded_groups.remove(id.as_str()) {
+                    expanded_groups.insert(id.to_string());
+                }
+
+                Rc::new(Self {
+                    expanded_groups,
+                    ..(*self).clone()
+                })
+            }
+            Self::Action::ExpandGroup(id) if self.is_group_expanded(&id) => self,
+            Self::Action::ExpandGroup(id) => {
+                let mut expanded_groups = self.expanded_groups.clone();
+                expanded_groups.insert(id.to_string());
+
+                Rc::new(Self {
+                    expanded_groups,
+                    ..(*self).clone()
+                })
+            }
+            Self::Action::SetWidth(width) => Rc::new(Self {
+                width: Some(width),
+                ..(*self).clone()
+            }),
+            Self::Action::SetResizing(resizing) if resizing == self.resizing => self,
+            Self::Action::SetResizing(resizing) => Rc::new(Self {
+                resizing,
+                ..(*self).clone()
+            }),
+            Self::Action::SetSide(side) => Rc::new(Self {
+                side,
+                ..(*self).clone()
+            }),
+            Self::Action::SetVariant(variant) => Rc::new(Self {
+                variant,
+                ..(*self).clone()
             }),
         }
     }
 }
 
-pub type SidebarContext = UseReducerHandle<Sidebar>;
+/// Returns the media query matching viewports narrower than the breakpoint.
+fn mobile_media_query(breakpoint: u32) -> Option<MediaQueryList> {
+    let query = format!("(max-width: {}px)", breakpoint.saturating_sub(1));
+
+    gloo::utils::window().match_media(&query).ok().flatten()
+}
+
+/// The state of the sidebar, as seen by its components, and a way to change it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SidebarContext {
+    pub state: SidebarState,
+    /// The id of the element of the [`Sidebar`](crate::components::Sidebar), which its toggles
+    /// control.
+    pub element_id: AttrValue,
+    dispatcher: Callback<SidebarAction>,
+}
+
+impl SidebarContext {
+    pub fn dispatch(&self, action: SidebarAction) {
+        self.dispatcher.emit(action);
+    }
+
+    /// Returns the context seen by content shown expanded while the sidebar is collapsed, e.g. in
+    /// a flyout next to the icon rail.
+    pub fn expanded(&self) -> Self {
+        Self {
+            state: SidebarState {
+                open: true,
+                open_mobile: true,
+                ..self.state.clone()
+            },
+            element_id: self.element_id.clone(),
+            dispatcher: self.dispatcher.clone(),
+        }
+    }
+}
+
+/// The contexts of the [`SidebarProvider`]s with an id, to address a sidebar which isn't the
+/// closest one, e.g. a details sidebar next to the navigation one.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SidebarRegistry {
+    sidebars: Rc<Vec<(AttrValue, SidebarContext)>>,
+}
+
+impl SidebarRegistry {
+    pub fn get(&self, id: &str) -> Option<&SidebarContext> {
+        self.sidebars
+            .iter()
+            .find(|(sidebar_id, _)| sidebar_id == id)
+            .map(|(_, context)| context)
+    }
+
+    /// Returns the registry with the context of the sidebar with the id added, or replaced.
+    fn with(&self, id: AttrValue, context: SidebarContext) -> Self {
+        let mut sidebars: Vec<_> = self
+            .sidebars
+            .iter()
+            .filter(|(sidebar_id, _)| *sidebar_id != id)
+            .cloned()
+            .collect();
+        sidebars.push((id, context));
+
+        Self {
+            sidebars: Rc::new(sidebars),
+        }
+    }
+}
+
+/// Returns the context of the sidebar provided with the id, or of the closest one if `None`.
+pub fn find_sidebar_context<C: Component>(
+    ctx: &Context<C>,
+    id: Option<&AttrValue>,
+) -> Option<SidebarContext> {
+    match id {
+        Some(id) => ctx
+            .link()
+            .context::<SidebarRegistry>(Callback::noop())
+            .and_then(|(registry, _)| registry.get(id).cloned()),
+        None => ctx
+            .link()
+            .context::<SidebarContext>(Callback::noop())
+            .map(|(context, _)| context),
+    }
+}
+
+/// Returns the context of the sidebar provided with the id, or of the closest one if `None`.
+#[hook]
+pub fn use_sidebar(id: Option<AttrValue>) -> Option<SidebarContext> {
+    let closest = use_context::<SidebarContext>();
+    let registry = use_context::<SidebarRegistry>();
+
+    match id {
+        Some(id) => registry.and_then(|registry| registry.get(&id).cloned()),
+        None => closest,
+    }
+}
 
 #[derive(Debug, PartialEq, Properties)]
 pub struct SidebarProviderProperties {
     #[prop_or_default]
     pub children: Children,
+    /// Makes the sidebar addressable from within the other providers nested in this one, e.g. by
+    /// `<SidebarToggle target="nav">` or `<Sidebar id="nav">`.
+    #[prop_or_default]
+    pub id: Option<AttrValue>,
     #[prop_or_default]
     pub default_open: bool,
+    /// The initial side, which can be changed with [`SidebarAction::SetSide`].
+    #[prop_or_default]
+    pub default_side: SidebarSide,
+    /// The initial variant, which can be changed with [`SidebarAction::SetVariant`].
+    #[prop_or_default]
+    pub default_variant: SidebarVariant,
+    /// Makes the open state controlled: the sidebar is open if `true`, and changes are only
+    /// reported through `on_open_change`.
+    #[prop_or_default]
+    pub open: Option<bool>,
+    /// Called with the new open state when the sidebar is toggled on desktop.
+    #[prop_or_default]
+    pub on_open_change: Callback<bool>,
+    /// The `localStorage` key to persist the open state under, the expanded groups and the width
+    /// are persisted under the same key suffixed by `-groups` and `-width`. Nothing is persisted
+    /// if `None`.
+    #[prop_or_default]
+    pub storage_key: Option<AttrValue>,
+    /// The viewport width in pixels under which the sidebar is shown over the page.
+    #[prop_or(768)]
+    pub mobile_breakpoint: u32,
+    /// The keyboard shortcut toggling the sidebar from anywhere on the page, `mod+b` by default.
+    /// `None` disables it. Each of several sidebars needs its own.
+    #[prop_or_else(default_shortcut)]
+    pub shortcut: Option<KeyCombo>,
+    /// Whether the shortcut is ignored while typing in a text field, e.g. where Ctrl+B is bold.
+    #[prop_or(true)]
+    pub ignore_shortcut_in_text_fields: bool,
 }
 
+/// Provides the [`SidebarContext`] to the sidebar components.
+///
+/// Under the `mobile_breakpoint`, the sidebar is shown over the page with its own open state, which
+/// is closed by Escape and when the route changes. The desktop open state is the one that can be
+/// controlled and persisted.
+///
+/// Usage:
+/// ```ignore
+/// // Uncontrolled, restored from and persisted to `localStorage`
+/// <SidebarProvider default_open=true storage_key="sidebar-open">
+///     <Sidebar>{ "..." }</Sidebar>
+/// </SidebarProvider>
+///
+/// // With another shortcut
+/// <SidebarProvider shortcut={"ctrl+shift+s".parse::<KeyCombo>().ok()}>
+///     <Sidebar>{ "..." }</Sidebar>
+/// </SidebarProvider>
+///
+/// // Several sidebars, nested around the page so that each can be addressed by its id
+/// <SidebarProvider id="nav">
+///     <SidebarProvider id="details" shortcut={"mod+.".parse::<KeyCombo>().ok()}>
+///         <Sidebar id="nav">{ "..." }</Sidebar>
+///         <SidebarToggle target="details">{ "Details" }</SidebarToggle>
+///         <Sidebar id="details">{ "..." }</Sidebar>
+///     </SidebarProvider>
+/// </SidebarProvider>
+///
+/// // Controlled, e.g. collapsed in focus mode
+/// <SidebarProvider open={!*focus_mode} on_open_change={on_sidebar_open_change}>
+///     <Sidebar>{ "..." }</Sidebar>
+/// </SidebarProvider>
+/// ```
 #[function_component]
 pub fn SidebarProvider(props: &SidebarProviderProperties) -> Html {
     let SidebarProviderProperties {
         children,
+        id,
         default_open,
-        ..
+        default_side,
+        default_variant,
+        open,
+        on_open_change,
+        storage_key,
+        mobile_breakpoint,
+        shortcut,
+        ignore_shortcut_in_text_fields,
     } = props;
 
-    let context = use_reducer(|| Sidebar {
-        is_open: *default_open,
+    // Restored while creating the state, so that the first render is already in the right state.
+    let uncontrolled = use_reducer({
+        let storage_key = storage_key.clone();
+        let default_open = *default_open;
+        let side = *default_side;
+        let variant = *default_variant;
+        let mobile_breakpoint = *mobile_breakpoint;
+        move || SidebarState {
+            open: storage_key
+                .as_ref()
+                .and_then(|key| LocalStorage::get(key.as_str()).ok())
+                .unwrap_or(default_open),
+            open_mobile: false,
+            is_mobile: mobile_media_query(mobile_breakpoint).is_some_and(|query| query.matches()),
+            expanded_groups: storage_key
+                .as_ref()
+                .and_then(|key| LocalStorage::get(groups_storage_key(key)).ok())
+                .unwrap_or_default(),
+            width: storage_key.and_then(|key| LocalStorage::get(width_storage_key(&key)).ok()),
+            resizing: false,
+            side,
+            variant,
+        }
     });
 
-    html! {
-        <ContextProvider<SidebarContext> context={context.clone()}>
-            <div
-                style="outline: none"
-                tabindex="-1"
-                onkeydown={Callback::from(move |e: KeyboardEvent| {
-                    if e.key() == SIDEBAR_KEYBOARD_SHORTCUT && (e.meta_key() || e.ctrl_key()) {
+    let state = SidebarState {
+        open: open.unwrap_or(uncontrolled.open),
+        ..(*uncontrolled).clone()
+    };
+
+    // The listener of the media query, recreated when the breakpoint changes.
+    let media_listener = use_mut_ref(|| None::<(u32, EventListener)>);
+    {
+        let mobile_breakpoint = *mobile_breakpoint;
+        let dispatcher = uncontrolled.dispatcher();
+        use_effect(move || {
+            let is_current = media_listener
+                .borrow()
+                .as_ref()
+                .is_some_and(|(breakpoint, _)| *breakpoint == mobile_breakpoint);
+            if !is_current && let Some(query) = mobile_media_query(mobile_breakpoint) {
+                dispatcher.dispatch(SidebarAction::SetMobile(query.matches()));
+
+                let listener = EventListener::new(&query.clone(), "change", move |_| {
+                    dispatcher.dispatch(SidebarAction::SetMobile(query.matches()))
+                });
+                *media_listener.borrow_mut() = Some((mobile_breakpoint, listener));
+            }
+        });
+    }
+
+    // Navigating from the mobile sidebar closes it.
+    let path = use_location().map(|location| location.path().to_string());
+    let last_path = use_mut_ref(|| path.clone());
+    {
+        let dispatcher = uncontrolled.dispatcher();
+        let open_mobile = state.open_mobile;
+        use_effect(move || {
+            let changed = *last_path.borrow() != path;
+            if changed {
+                *last_path.borrow_mut() = path;
+                if open_mobile {
+                    dispatcher.dispatch(SidebarAction::Close);
+                }
+            }
+        });
+    }
+
+    // Only what changed is written, the open state only when it isn't controlled, and the width
+    // once resized.
+    let persisted = use_mut_ref(|| None::<Persisted>);
+    {
+        let next = Persisted {
+            key: storage_key.clone(),
+            open: open.is_none().then_some(state.open),
+            expanded_groups: state.expanded_groups.clone(),
+            width: state.width.filter(|_| !state.resizing),
+        };
+        use_effect(move || {
+            let previous = persisted.borrow_mut().replace(next.clone());
+            let previous = previous.filter(|previous| previous.key == next.key);
+            let Some(key) = &next.key else {
+                return;
+            };
+
+            if let Some(open) = next.open
+                && previous.as_ref().is_none_or(|p| p.open != next.open)
+            {
+                let _ = LocalStorage::set(key.as_str(), open);
+            }
+            if previous
+                .as_ref()
+                .is_none_or(|p| p.expanded_groups != next.expanded_groups)
+            {
+                let _ = LocalStorage::set(groups_storage_key(key), &next.expanded_groups);
+            }
+            if let Some(width) = next.width
+                && previous.as_ref().is_none_or(|p| p.width != next.width)
+            {
+                let _ = LocalStorage::set(width_storage_key(key), width);
+            }
+        });
+    }
+
+    // The actions are reduced against the state with the actions dispatched since the last
+    // render, so that several of them dispatched at once see each other, e.g. two toggles.
+    let live_state = use_mut_ref(|| state.clone());
+    *live_state.borrow_mut() = state.clone();
+
+    let dispatcher = {
+        let is_controlled = open.is_some();
+        let uncontrolled = uncontrolled.dispatcher();
+        let on_open_change = on_open_change.clone();
+        Callback::from(move |action: SidebarAction| {
+            let open_change =
+                reduce_live(&mut live_state.borrow_mut(), action.clone(), is_controlled);
+
+            if let Some(open) = open_change {
+                on_open_change.emit(open);
+            }
+            // Only the open state is controlled, the rest of the state always changes.
+            if !is_controlled || open_change.is_none() {
+                uncontrolled.dispatch(action);
+            }
+        })
+    };
+
+    let generated_id = use_state(next_element_id);
+    let element_id = match id {
+        Some(id) => element_id(id),
+        None => (*generated_id).clone(),
+    };
+
+    let context = SidebarContext {
+        state,
+        element_id,
+        dispatcher,
+    };
+
+    // Listened on the window, so that it works wherever the focus is. Registered again on every
+    // render to see the current state, and removed on unmount.
+    {
+        let context = context.clone();
+        let shortcut = shortcut.clone();
+        let ignore_shortcut_in_text_fields = *ignore_shortcut_in_text_fields;
+        use_effect(move || {
+            let listener = EventListener::new_with_options(
+                &gloo::utils::window(),
+                "keydown",
+                EventListenerOptions::enable_prevent_default(),
+                move |e| {
+                    let Some(e) = e.dyn_ref::<KeyboardEvent>() else {
+                        return;
+                    };
+                    // The page behind a modal is inert, it doesn't get shortcuts either.
+                    if e.default_prevented() || any_modal_open() {
+                        return;
+                    }
+
+                    let is_shortcut = shortcut.as_ref().is_some_and(|shortcut| {
+                        shortcut.matches(e)
+                            && !(ignore_shortcut_in_text_fields && is_text_field(e.target()))
+                    });
+                    if is_shortcut {
+                        e.prevent_default();
                         context.dispatch(SidebarAction::Toggle);
+                    } else if e.key() == "Escape"
+                        && context.state.is_mobile
+                        && context.state.open_mobile
+                    {
+                        context.dispatch(SidebarAction::Close);
                     }
-                })}
-            >
-                { children.clone() }
-            </div>
+                },
+            );
+
+            move || drop(listener)
+        });
+    }
+
+    let registry = use_context::<SidebarRegistry>();
+
+    let content = html! {
+        <ContextProvider<SidebarContext> context={context.clone()}>
+            { children.clone() }
         </ContextProvider<SidebarContext>>
+    };
+
+    match id {
+        Some(id) => html! {
+            <ContextProvider<SidebarRegistry>
+                context={registry.unwrap_or_default().with(id.clone(), context)}
+            >
+                { content }
+            </ContextProvider<SidebarRegistry>>
+        },
+        None => content,
     }
 }
 
@@ -72,18 +639,187 @@ mod tests {
     #[test]
     fn html_with_all_props() {
         let _ = html! {
-            <SidebarProvider default_open=true>
+            <SidebarProvider
+                id="nav"
+                default_open=true
+                default_side={SidebarSide::Right}
+                default_variant={SidebarVariant::Floating}
+                open=false
+                on_open_change={Callback::from(|open| log::info!("Sidebar open: {open}"))}
+                storage_key="sidebar-open"
+                mobile_breakpoint=1024
+                shortcut={"ctrl+shift+s".parse::<KeyCombo>().ok()}
+                ignore_shortcut_in_text_fields=false
+            >
                 <div>{ "Sidebar" }</div>
             </SidebarProvider>
         };
     }
 
     #[test]
-    fn tabs_state_reducer() {
-        let initial_state = Sidebar { is_open: true };
+    fn sidebar_state_reducer() {
+        let initial_state = SidebarState {
+            open: true,
+            open_mobile: false,
+            is_mobile: false,
+            ..SidebarState::default()
+        };
+
+        let reduced = SidebarState::reduce(Rc::new(initial_state), SidebarAction::Toggle);
+
+        assert!(!reduced.open);
+        assert!(!reduced.is_expanded());
+    }
+
+    #[test]
+    fn sidebar_state_reducer_on_mobile() {
+        let initial_state = SidebarState {
+            open: true,
+            open_mobile: false,
+            is_mobile: false,
+            ..SidebarState::default()
+        };
+
+        let mobile = SidebarState::reduce(Rc::new(initial_state), SidebarAction::SetMobile(true));
+        assert!(!mobile.is_expanded());
+
+        let toggled = SidebarState::reduce(mobile, SidebarAction::Toggle);
+        assert!(toggled.open_mobile);
+        assert!(toggled.open, "the desktop state is kept");
+
+        let closed = SidebarState::reduce(toggled.clone(), SidebarAction::Close);
+        assert!(!closed.open_mobile);
+
+        let desktop = SidebarState::reduce(toggled, SidebarAction::SetMobile(false));
+        assert!(!desktop.open_mobile);
+        assert!(desktop.is_expanded());
+    }
+
+    #[test]
+    fn sidebar_state_reducer_groups() {
+        let initial_state = SidebarState::default();
+
+        let toggled = SidebarState::reduce(
+            Rc::new(initial_state),
+            SidebarAction::ToggleGroup("overlays".into()),
+        );
+        assert!(toggled.is_group_expanded("overlays"));
+        assert!(!toggled.is_group_expanded("navigation"));
+
+        let expanded = SidebarState::reduce(
+            toggled.clone(),
+            SidebarAction::ExpandGroup("overlays".into()),
+        );
+        assert!(Rc::ptr_eq(&toggled, &expanded), "already expanded");
+
+        let collapsed =
+            SidebarState::reduce(expanded, SidebarAction::ToggleGroup("overlays".into()));
+        assert!(!collapsed.is_group_expanded("overlays"));
+
+        let expanded =
+            SidebarState::reduce(collapsed, SidebarAction::ExpandGroup("navigation".into()));
+        assert!(expanded.is_group_expanded("navigation"));
+    }
+
+    #[test]
+    fn sidebar_state_reducer_width() {
+        let initial_state = SidebarState::default();
+        assert_eq!(initial_state.width, None);
+
+        let resized = SidebarState::reduce(Rc::new(initial_state), SidebarAction::SetWidth(320.0));
+        assert_eq!(resized.width, Some(320.0));
+
+        let resizing = SidebarState::reduce(resized, SidebarAction::SetResizing(true));
+        assert!(resizing.resizing);
+        let still_resizing =
+            SidebarState::reduce(resizing.clone(), SidebarAction::SetResizing(true));
+        assert!(Rc::ptr_eq(&resizing, &still_resizing), "already resizing");
+    }
+
+    #[test]
+    fn sidebar_state_reducer_open_close() {
+        let initial_state = Rc::new(SidebarState::default());
+
+        let opened = SidebarState::reduce(initial_state.clone(), SidebarAction::Open);
+        assert!(opened.open);
+        let still_opened = SidebarState::reduce(opened.clone(), SidebarAction::Open);
+        assert!(Rc::ptr_eq(&opened, &still_opened), "already open");
+
+        let closed = SidebarState::reduce(opened.clone(), SidebarAction::Close);
+        assert!(!closed.open);
+        let still_closed = SidebarState::reduce(closed.clone(), SidebarAction::Close);
+        assert!(Rc::ptr_eq(&closed, &still_closed), "already closed");
+
+        assert!(SidebarState::reduce(initial_state.clone(), SidebarAction::Set(true)).open);
+        assert!(!SidebarState::reduce(opened, SidebarAction::Set(false)).open);
+
+        let mobile = SidebarState::reduce(closed, SidebarAction::SetMobile(true));
+        let opened_mobile = SidebarState::reduce(mobile, SidebarAction::Open);
+        assert!(opened_mobile.open_mobile);
+        assert!(!opened_mobile.open, "the desktop state is kept");
+
+        let closed_mobile = SidebarState::reduce(opened_mobile, SidebarAction::Set(false));
+        assert!(!closed_mobile.open_mobile);
+    }
+
+    #[test]
+    fn element_ids() {
+        assert_eq!(element_id("nav"), "sidebar-nav");
+        assert_ne!(
+            element_id("0"),
+            next_element_id(),
+            "explicit ids don't collide with generated ones"
+        );
+    }
+
+    #[test]
+    fn live_state_reducer() {
+        let mut live = SidebarState::default();
+        assert_eq!(
+            reduce_live(&mut live, SidebarAction::Toggle, false),
+            Some(true)
+        );
+        assert_eq!(
+            reduce_live(&mut live, SidebarAction::Toggle, false),
+            Some(false),
+            "sees the previous toggle"
+        );
+        assert!(!live.open);
+
+        // The parent rejects the change by not rendering with a new `open`.
+        let mut live = SidebarState::default();
+        assert_eq!(
+            reduce_live(&mut live, SidebarAction::Toggle, true),
+            Some(true)
+        );
+        assert!(!live.open, "the controlled state is kept");
+        assert_eq!(
+            reduce_live(&mut live, SidebarAction::Toggle, true),
+            Some(true),
+            "the next toggle asks again"
+        );
+
+        assert_eq!(
+            reduce_live(&mut live, SidebarAction::SetWidth(320.0), true),
+            None
+        );
+        assert_eq!(live.width, Some(320.0));
+    }
+
+    #[test]
+    fn sidebar_state_reducer_layout() {
+        let initial_state = SidebarState::default();
+        assert_eq!(initial_state.side, SidebarSide::Left);
+        assert_eq!(initial_state.variant, SidebarVariant::Sidebar);
 
-        let reduced = Sidebar::reduce(Rc::new(initial_state), SidebarAction::Toggle);
+        let right = SidebarState::reduce(
+            Rc::new(initial_state),
+            SidebarAction::SetSide(SidebarSide::Right),
+        );
+        assert_eq!(right.side, SidebarSide::Right);
 
-        assert!(!reduced.is_open);
+        let inset = SidebarState::reduce(right, SidebarAction::SetVariant(SidebarVariant::Inset));
+        assert_eq!(inset.variant, SidebarVariant::Inset);
+        assert_eq!(inset.side, SidebarSide::Right);
     }
 }
@@ -3,47 +3,50 @@ use std::rc::Rc;
 use yew::prelude::*;
 
 #[derive(Clone, Debug, PartialEq)]
-pub struct TabsState {
-    pub selected_tab: AttrValue,
+pub struct TabsState<T = AttrValue> {
+    pub selected_tab: Option<T>,
 }
 
 #[derive(Debug)]
-pub enum TabsAction {
-    Select(AttrValue),
+pub enum TabsAction<T = AttrValue> {
+    Select(T),
 }
 
-impl Reducible for TabsState {
-    type Action = TabsAction;
+impl<T: Clone + PartialEq + 'static> Reducible for TabsState<T> {
+    type Action = TabsAction<T>;
 
     fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
         match action {
             Self::Action::Select(value) => Rc::new(Self {
-                selected_tab: value,
+                selected_tab: Some(value),
             }),
         }
     }
 }
 
 #[derive(Clone, Debug, PartialEq)]
-pub struct TabsContext {
-    pub state: UseReducerHandle<TabsState>,
-    pub on_change: Callback<AttrValue>,
+pub struct TabsContext<T: Clone + PartialEq + 'static = AttrValue> {
+    pub state: UseReducerHandle<TabsState<T>>,
+    pub on_change: Callback<T>,
 }
 
 #[derive(Debug, PartialEq, Properties)]
-pub struct TabsProviderProperties {
+pub struct TabsProviderProperties<T: Clone + PartialEq + 'static = AttrValue> {
     #[prop_or_default]
     pub children: Children,
     #[prop_or_default]
-    pub default_value: Option<AttrValue>,
+    pub default_value: Option<T>,
     #[prop_or_default]
-    pub on_change: Callback<AttrValue>,
+    pub on_change: Callback<T>,
 }
 
+/// Provides the [`TabsContext`] for the tabs with values of type `T`.
 #[function_component]
-pub fn TabsProvider(props: &TabsProviderProperties) -> Html {
+pub fn TabsProvider<T: Clone + PartialEq + 'static = AttrValue>(
+    props: &TabsProviderProperties<T>,
+) -> Html {
     let state = use_reducer(|| TabsState {
-        selected_tab: props.default_value.clone().unwrap_or_default(),
+        selected_tab: props.default_value.clone(),
     });
 
     let context = TabsContext {
@@ -52,9 +55,9 @@ pub fn TabsProvider(props: &TabsProviderProperties) -> Html {
     };
 
     html! {
-        <ContextProvider<TabsContext> {context}>
+        <ContextProvider<TabsContext<T>> {context}>
             { props.children.clone() }
-        </ContextProvider<TabsContext>>
+        </ContextProvider<TabsContext<T>>>
     }
 }
 
@@ -63,6 +66,12 @@ mod tests {
     use super::*;
     // use wasm_bindgen_test::*;
 
+    #[derive(Clone, Debug, PartialEq)]
+    enum Flower {
+        Dandelion,
+        Heather,
+    }
+
     #[test]
     fn render_tabs_provider() {
         let _ = html! { <TabsProvider /> };
@@ -82,14 +91,26 @@ mod tests {
     #[test]
     fn tabs_state_reducer() {
         let initial_state = TabsState {
-            selected_tab: AttrValue::from("initial"),
+            selected_tab: Some(AttrValue::from("initial")),
         };
 
         let new_tab = AttrValue::from("new_tab");
         let reduced =
             TabsState::reduce(Rc::new(initial_state), TabsAction::Select(new_tab.clone()));
 
-        assert_eq!(reduced.selected_tab, new_tab);
+        assert_eq!(reduced.selected_tab, Some(new_tab));
+    }
+
+    #[test]
+    fn tabs_state_reducer_with_typed_values() {
+        let initial_state = TabsState { selected_tab: None };
+
+        let reduced =
+            TabsState::reduce(Rc::new(initial_state), TabsAction::Select(Flower::Heather));
+        assert_eq!(reduced.selected_tab, Some(Flower::Heather));
+
+        let reduced = TabsState::reduce(reduced, TabsAction::Select(Flower::Dandelion));
+        assert_eq!(reduced.selected_tab, Some(Flower::Dandelion));
     }
 
     // TODO: Add test for context.
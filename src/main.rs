@@ -5,43 +5,119 @@ mod components;
 mod contexts;
 mod pages;
 mod route;
+mod utils;
 
-use components::{CollapsedMode, Sidebar, SidebarContent, SidebarFooter};
-use components::{SidebarHeader, SidebarToggle};
-use contexts::SidebarProvider;
+use components::{CollapsedMode, ErrorBoundary, Sidebar, SidebarContent, SidebarFooter};
+use components::{SidebarGroup, SidebarHeader, SidebarItem, SidebarLandmark, SidebarMenu};
+use components::{SidebarMenuButton, SidebarMenuItem, SidebarRail, SidebarToggle};
+use contexts::{DialogProvider, PortalProvider, SidebarProvider, SidebarSide};
 
 use route::Route;
 use route::switch;
+use utils::KeyCombo;
 
 #[function_component(App)]
 fn app() -> Html {
     html! {
         <BrowserRouter>
-            <div id="app-root">
-                <SidebarProvider>
-                    <div style="display: flex">
-                        <Sidebar>
-                            <SidebarHeader style="padding: 0.5rem;">
-                                <SidebarToggle>{ "Menu" }</SidebarToggle>
-                            </SidebarHeader>
-                            <SidebarContent collapsible={CollapsedMode::Hidden}>
-                                <div>{ "Content 1" }</div>
-                                <div style="display: flex; gap: 0.5rem">
-                                    <div>{ "I" }</div>
-                                    <div>{ "am" }</div>
-                                    <div>{ "Content" }</div>
-                                    <div>{ "2" }</div>
+            <PortalProvider container_id="modal-root" inert_target_id="app-root">
+                <div id="app-root">
+                    <DialogProvider>
+                        <SidebarProvider id="nav" storage_key="sidebar-open">
+                            <SidebarProvider
+                                id="details"
+                                default_side={SidebarSide::Right}
+                                shortcut={"mod+.".parse::<KeyCombo>().ok()}
+                            >
+                                <div style="display: flex">
+                                    <Sidebar id="nav" label="Main">
+                                        <SidebarHeader style="padding: 0.5rem;">
+                                            <SidebarToggle>{ "Menu" }</SidebarToggle>
+                                        </SidebarHeader>
+                                        <SidebarContent collapsible={CollapsedMode::Icon}>
+                                            <SidebarItem collapsible={CollapsedMode::Hidden}>
+                                                { "Components" }
+                                            </SidebarItem>
+                                            <SidebarMenu>
+                                                <SidebarMenuItem>
+                                                    <SidebarMenuButton<Route>
+                                                        to={Route::Home}
+                                                        label="Home"
+                                                        icon={html! { { "⌂" } }}
+                                                    />
+                                                </SidebarMenuItem>
+                                                <SidebarMenuItem>
+                                                    <SidebarGroup id="overlays" label="Overlays" icon={html! { { "❐" } }}>
+                                                        <SidebarMenu>
+                                                            <SidebarMenuItem>
+                                                                <SidebarMenuButton<Route>
+                                                                    to={Route::Dialog}
+                                                                    label="Dialog"
+                                                                    icon={html! { { "▢" } }}
+                                                                />
+                                                            </SidebarMenuItem>
+                                                        </SidebarMenu>
+                                                    </SidebarGroup>
+                                                </SidebarMenuItem>
+                                                <SidebarMenuItem>
+                                                    <SidebarGroup id="navigation" label="Navigation" icon={html! { { "☰" } }}>
+                                                        <SidebarMenu>
+                                                            <SidebarMenuItem>
+                                                                <SidebarMenuButton<Route>
+                                                                    to={Route::Pagination}
+                                                                    label="Pagination"
+                                                                    icon={html! { { "…" } }}
+                                                                />
+                                                            </SidebarMenuItem>
+                                                            <SidebarMenuItem>
+                                                                <SidebarMenuButton<Route>
+                                                                    to={Route::Tabs}
+                                                                    label="Tabs"
+                                                                    icon={html! { { "⊟" } }}
+                                                                />
+                                                            </SidebarMenuItem>
+                                                            <SidebarMenuItem>
+                                                                <SidebarMenuButton<Route>
+                                                                    to={Route::SegmentedControl}
+                                                                    label="Segmented Control"
+                                                                    icon={html! { { "◫" } }}
+                                                                />
+                                                            </SidebarMenuItem>
+                                                        </SidebarMenu>
+                                                    </SidebarGroup>
+                                                </SidebarMenuItem>
+                                            </SidebarMenu>
+                                        </SidebarContent>
+                                        <SidebarFooter collapsible={CollapsedMode::Hidden}>
+                                            { "Footer" }
+                                        </SidebarFooter>
+                                        <SidebarRail />
+                                    </Sidebar>
+                                    <ErrorBoundary>
+                                        <Switch<Route> render={switch} />
+                                    </ErrorBoundary>
+                                    <Sidebar
+                                        id="details"
+                                        landmark={SidebarLandmark::Complementary}
+                                        label="Details"
+                                    >
+                                        <SidebarHeader style="padding: 0.5rem;">
+                                            <SidebarToggle>{ "Details" }</SidebarToggle>
+                                        </SidebarHeader>
+                                        <SidebarContent
+                                            collapsible={CollapsedMode::Hidden}
+                                            style="padding: 0.5rem;"
+                                        >
+                                            { "Press Ctrl+. to toggle the details." }
+                                        </SidebarContent>
+                                    </Sidebar>
                                 </div>
-                            </SidebarContent>
-                            <SidebarFooter collapsible={CollapsedMode::Hidden}>
-                                { "Footer" }
-                            </SidebarFooter>
-                        </Sidebar>
-                        <Switch<Route> render={switch} />
-                    </div>
-                </SidebarProvider>
-            </div>
-            <div id="modal-root" />
+                            </SidebarProvider>
+                        </SidebarProvider>
+                    </DialogProvider>
+                </div>
+                <div id="modal-root" />
+            </PortalProvider>
         </BrowserRouter>
     }
 }
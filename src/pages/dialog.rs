@@ -1,8 +1,12 @@
+use std::time::Duration;
+
+use futures::FutureExt;
+use web_sys::FormData;
 use yew::prelude::*;
-use yew_router::prelude::Link;
 
 use crate::Route;
 use crate::components::*;
+use crate::contexts::{DialogOptions, use_dialog};
 
 macro_rules! make_toggle_callbacks {
     ($state:expr) => {{
@@ -31,15 +35,76 @@ pub fn dialog() -> Html {
     let is_long_dialog_open = use_state(|| false);
     let is_form_dialog_open = use_state(|| false);
     let is_tabs_dialog_open = use_state(|| false);
+    let is_alert_dialog_open = use_state(|| false);
+    let is_inspector_open = use_state(|| false);
+    let sheet_side = use_state(|| None::<SheetSide>);
 
     let (toggle_long_dialog, toggle_long_dialog_call) = make_toggle_callbacks!(is_long_dialog_open);
     let (toggle_form_dialog, toggle_form_dialog_call) = make_toggle_callbacks!(is_form_dialog_open);
     let (toggle_tabs_dialog, toggle_tabs_dialog_call) = make_toggle_callbacks!(is_tabs_dialog_open);
+    let (toggle_alert_dialog, toggle_alert_dialog_call) =
+        make_toggle_callbacks!(is_alert_dialog_open);
+    let (toggle_inspector, toggle_inspector_call) = make_toggle_callbacks!(is_inspector_open);
+
+    let open_sheet = |side: SheetSide| {
+        let sheet_side = sheet_side.clone();
+        Callback::from(move |_| sheet_side.set(Some(side)))
+    };
+
+    let close_sheet = {
+        let sheet_side = sheet_side.clone();
+        Callback::from(move |_| sheet_side.set(None))
+    };
+
+    let dialog = use_dialog();
+
+    let delete_file = {
+        let dialog = dialog.clone();
+        Callback::from(move |_| {
+            let dialog = dialog.clone();
+            yew::platform::spawn_local(async move {
+                let confirmed = dialog
+                    .confirm(DialogOptions {
+                        title: "Delete the file?".into(),
+                        message: "This action cannot be undone.".into(),
+                        confirm_label: "Delete".into(),
+                        ..Default::default()
+                    })
+                    .await;
+
+                if confirmed {
+                    dialog
+                        .alert(DialogOptions {
+                            title: "File deleted".into(),
+                            ..Default::default()
+                        })
+                        .await;
+                }
+            });
+        })
+    };
+
+    let rename_file = Callback::from(move |_| {
+        let dialog = dialog.clone();
+        yew::platform::spawn_local(async move {
+            let name = dialog
+                .prompt(DialogOptions {
+                    title: "Rename the file".into(),
+                    default_value: "untitled.txt".into(),
+                    ..Default::default()
+                })
+                .await;
+
+            if let Some(name) = name {
+                log::info!("Renamed to {name}");
+            }
+        });
+    });
 
     html! {
         <div style="display: flex; flex-direction: column; gap: 20px; padding: 20px">
             <h1>{ "Dialog Showcase" }</h1>
-            <Link<Route> to={Route::Home}>{ "Home" }</Link<Route>>
+            <Breadcrumbs<Route> />
             <button style="width: fit-content" onclick={toggle_long_dialog}>
                 { "Long Dialog" }
             </button>
@@ -49,9 +114,46 @@ pub fn dialog() -> Html {
             <button style="width: fit-content" onclick={toggle_tabs_dialog}>
                 { "Tabs Dialog" }
             </button>
+            <button style="width: fit-content" onclick={toggle_alert_dialog}>
+                { "Delete Account" }
+            </button>
+            <button style="width: fit-content" onclick={toggle_inspector}>
+                { "Inspector" }
+            </button>
+            <div style="display: flex; gap: 0.5rem">
+                { for [SheetSide::Top, SheetSide::Right, SheetSide::Bottom, SheetSide::Left]
+                    .into_iter()
+                    .map(|side| html! {
+                        <button onclick={open_sheet(side)}>{ format!("{side:?} Sheet") }</button>
+                    }) }
+            </div>
+            <button style="width: fit-content" onclick={delete_file}>{ "Delete File" }</button>
+            <button style="width: fit-content" onclick={rename_file}>{ "Rename File" }</button>
             <LongDialog is_open={*is_long_dialog_open} handle_close={toggle_long_dialog_call} />
-            <FormDialog is_open={*is_form_dialog_open} handle_close={toggle_form_dialog_call} />
+            <RenameDialog is_open={*is_form_dialog_open} handle_close={toggle_form_dialog_call} />
             <TabsDialog is_open={*is_tabs_dialog_open} handle_close={toggle_tabs_dialog_call} />
+            <DeleteAccountDialog
+                is_open={*is_alert_dialog_open}
+                handle_close={toggle_alert_dialog_call}
+            />
+            <FiltersSheet
+                is_open={sheet_side.is_some()}
+                side={sheet_side.unwrap_or_default()}
+                handle_close={close_sheet}
+            />
+            <FloatingDialog
+                id="inspector"
+                open={*is_inspector_open}
+                on_close={toggle_inspector_call.reform(|_| ())}
+            >
+                <DialogHeader>
+                    <DialogTitle>{ "Inspector" }</DialogTitle>
+                    <button onclick={toggle_inspector_call.reform(|_| ())}>{ "Close" }</button>
+                </DialogHeader>
+                <DialogContent>
+                    { "Drag me by the header, resize me from the edges. The page stays interactive." }
+                </DialogContent>
+            </FloatingDialog>
         </div>
     }
 }
@@ -77,7 +179,7 @@ pub fn long_dialog(
     };
 
     html! {
-        <Dialog open={*is_open} on_close={handle_close}>
+        <Dialog open={*is_open} size={DialogSize::Lg} on_close={handle_close.reform(|_| ())}>
             <DialogHeader>
                 <DialogTitle>{ "This is a long dialog" }</DialogTitle>
             </DialogHeader>
@@ -102,100 +204,61 @@ Quisque condimentum quis sapien quis consectetur. Quisque ornare sit amet augue
 }
 
 #[derive(Debug, Properties, PartialEq)]
-pub struct FormDialogProps {
+pub struct RenameDialogProps {
     is_open: bool,
     handle_close: Callback<()>,
 }
 
-#[function_component(FormDialog)]
-pub fn form_dialog(
-    FormDialogProps {
+#[function_component(RenameDialog)]
+pub fn rename_dialog(
+    RenameDialogProps {
         is_open,
         handle_close,
-    }: &FormDialogProps,
+    }: &RenameDialogProps,
 ) -> Html {
-    let value = use_state(|| 0);
-
-    let on_change = {
-        let value = value.clone();
-        Callback::from(move |val| value.set(val))
-    };
+    let on_submit = Callback::from(|data: FormData| {
+        async move {
+            let name = data.get("name").as_string().unwrap_or_default();
+            if name.trim().is_empty() {
+                return Err("The name is required".to_string());
+            }
 
-    let clear_value = {
-        let value = value.clone();
-        Callback::from(move |_| {
-            value.set(0);
-        })
-    };
+            // Pretend to save the name on a server.
+            yew::platform::time::sleep(Duration::from_secs(1)).await;
+            if name.contains('/') {
+                return Err(format!("\"{name}\" can't contain \"/\""));
+            }
 
-    let handle_close_for_click = {
-        let handle_close = handle_close.clone();
-        Callback::from(move |_| {
-            handle_close.emit(());
-        })
-    };
+            log::info!("Renamed to {name}");
+            Ok(())
+        }
+        .boxed_local()
+    });
 
-    let handle_submit = {
-        let value = value.clone();
-        let clear_value = clear_value.clone();
-        let handle_close = handle_close.clone();
-        Callback::from(move |_| {
-            log::info!("Submit: {}", *value);
-            handle_close.emit(());
-            clear_value.emit(());
-        })
-    };
+    let before_close = Callback::from(|_| gloo::dialogs::confirm("Discard changes?"));
 
     html! {
-        <Dialog open={*is_open} on_close={handle_close}>
+        <FormDialog<String>
+            open={*is_open}
+            on_close={handle_close.reform(|_| ())}
+            {on_submit}
+            close_on_backdrop=false
+            {before_close}
+        >
             <DialogHeader>
                 <DialogTitle>{ "This is a dialog with form" }</DialogTitle>
             </DialogHeader>
             <DialogContent>
-                <Counter value={*value} {on_change} />
-                <button onclick={handle_close_for_click.clone()}>{ "Hide" }</button>
+                <label style="display: flex; flex-direction: column; gap: 0.5rem">
+                    { "Project name" }
+                    <input name="name" value="untitled" />
+                </label>
             </DialogContent>
             <DialogFooter>
-                <button
-                    onclick={Callback::from(move |e: MouseEvent| {
-                    clear_value.emit(());
-                    handle_close_for_click.emit(e);
-                })}
-                >
-                    { "Clear" }
-                </button>
-                <button onclick={handle_submit}>{ "Submit" }</button>
+                <button type="button" onclick={handle_close.reform(|_| ())}>{ "Cancel" }</button>
+                <button type="submit">{ "Save" }</button>
             </DialogFooter>
-        </Dialog>
-    }
-}
-
-#[derive(Debug, Properties, PartialEq)]
-pub struct CounterProps {
-    value: i32,
-    on_change: Callback<i32>,
-}
-
-#[function_component(Counter)]
-pub fn counter(CounterProps { value, on_change }: &CounterProps) -> Html {
-    let decrement: Callback<MouseEvent> = {
-        let on_change = on_change.clone();
-        let value = *value;
-        Callback::from(move |_| on_change.emit(value - 1))
-    };
-
-    let increment = {
-        let on_change = on_change.clone();
-        let value = *value;
-        Callback::from(move |_| on_change.emit(value + 1))
-    };
-
-    html! {
-        <div style="display: flex; align-items: center; gap: 0.5rem">
-            <button onclick={decrement}>{ "-" }</button>
-            { *value }
-            <button onclick={increment}>{ "+" }</button>
-        </div>
+        </FormDialog<String>>
     }
 }
 
@@ -220,7 +283,7 @@ pub fn tabs_dialog(
     };
 
     html! {
-        <Dialog open={*is_open} on_close={handle_close}>
+        <Dialog open={*is_open} on_close={handle_close.reform(|_| ())}>
             <DialogHeader>
                 <DialogTitle>{ "This is a dialog with tabs" }</DialogTitle>
             </DialogHeader>
@@ -248,3 +311,88 @@ pub fn tabs_dialog(
         </Dialog>
     }
 }
+
+#[derive(Debug, Properties, PartialEq)]
+pub struct DeleteAccountDialogProps {
+    is_open: bool,
+    handle_close: Callback<()>,
+}
+
+#[function_component(DeleteAccountDialog)]
+pub fn delete_account_dialog(
+    DeleteAccountDialogProps {
+        is_open,
+        handle_close,
+    }: &DeleteAccountDialogProps,
+) -> Html {
+    let cancel_ref = use_node_ref();
+
+    let handle_delete = {
+        let handle_close = handle_close.clone();
+        Callback::from(move |_| {
+            log::info!("Account deleted");
+            handle_close.emit(());
+        })
+    };
+
+    html! {
+        <AlertDialog
+            open={*is_open}
+            on_close={handle_close.reform(|_| ())}
+            least_destructive_ref={cancel_ref.clone()}
+        >
+            <DialogHeader>
+                <DialogTitle>{ "Delete your account?" }</DialogTitle>
+            </DialogHeader>
+            <DialogContent>
+                { "Your account and all of its data will be permanently deleted." }
+            </DialogContent>
+            <DialogFooter>
+                <button ref={cancel_ref} onclick={handle_close.reform(|_| ())}>{ "Cancel" }</button>
+                <button onclick={handle_delete}>{ "Delete" }</button>
+            </DialogFooter>
+        </AlertDialog>
+    }
+}
+
+#[derive(Debug, Properties, PartialEq)]
+pub struct FiltersSheetProps {
+    is_open: bool,
+    side: SheetSide,
+    handle_close: Callback<()>,
+}
+
+#[function_component(FiltersSheet)]
+pub fn filters_sheet(
+    FiltersSheetProps {
+        is_open,
+        side,
+        handle_close,
+    }: &FiltersSheetProps,
+) -> Html {
+    let size = match side {
+        SheetSide::Top | SheetSide::Bottom => "50dvh",
+        SheetSide::Left | SheetSide::Right => "20rem",
+    };
+
+    html! {
+        <Sheet open={*is_open} side={*side} {size} on_close={handle_close.reform(|_| ())}>
+            <SheetHeader>
+                <SheetTitle>{ "Filters" }</SheetTitle>
+            </SheetHeader>
+            <SheetContent>
+                <label style="display: block">
+                    <input type="checkbox" />
+                    { "In stock" }
+                </label>
+                <label style="display: block">
+                    <input type="checkbox" />
+                    { "On sale" }
+                </label>
+            </SheetContent>
+            <SheetFooter>
+                <button onclick={handle_close.reform(|_| ())}>{ "Apply" }</button>
+            </SheetFooter>
+        </Sheet>
+    }
+}
@@ -1,8 +1,52 @@
 use yew::prelude::*;
 use yew_router::prelude::Link;
 
+use crate::components::SidebarToggle;
+use crate::contexts::{
+    SidebarAction, SidebarSide, SidebarVariant, use_error_boundary, use_sidebar,
+};
 use crate::route::Route;
 
+#[function_component(SidebarSettings)]
+fn sidebar_settings() -> Html {
+    let sidebar_context = use_sidebar(Some("nav".into())).expect("No sidebar context provided");
+
+    let button = |label: &'static str, action: SidebarAction| {
+        let sidebar_context = sidebar_context.clone();
+        let onclick = Callback::from(move |_| sidebar_context.dispatch(action.clone()));
+        html! { <button {onclick}>{ label }</button> }
+    };
+
+    html! {
+        <div style="display: flex; flex-wrap: wrap; gap: 10px">
+            { button("Open", SidebarAction::Open) }
+            { button("Close", SidebarAction::Close) }
+            { button("Left", SidebarAction::SetSide(SidebarSide::Left)) }
+            { button("Right", SidebarAction::SetSide(SidebarSide::Right)) }
+            { button("Sidebar", SidebarAction::SetVariant(SidebarVariant::Sidebar)) }
+            { button("Floating", SidebarAction::SetVariant(SidebarVariant::Floating)) }
+            { button("Inset", SidebarAction::SetVariant(SidebarVariant::Inset)) }
+            <SidebarToggle target="details">{ "Details" }</SidebarToggle>
+        </div>
+    }
+}
+
+#[function_component(ErrorSettings)]
+fn error_settings() -> Html {
+    let report = use_error_boundary();
+    let onreport = Callback::from(move |_| report.emit("The page failed to load".to_string()));
+    let onpanic = Callback::from(|_| panic!("The page panicked"));
+
+    html! {
+        <div style="display: flex; flex-wrap: wrap; gap: 10px">
+            <button onclick={onreport}>{ "Report an error" }</button>
+            if cfg!(debug_assertions) {
+                <button onclick={onpanic}>{ "Panic" }</button>
+            }
+        </div>
+    }
+}
+
 #[function_component(Home)]
 pub fn home() -> Html {
     html! {
@@ -13,6 +57,10 @@ pub fn home() -> Html {
                 <Link<Route> to={Route::Dialog}>{ "Dialog" }</Link<Route>>
                 <Link<Route> to={Route::Tabs}>{ "Tabs" }</Link<Route>>
                 <Link<Route> to={Route::SegmentedControl}>{ "Segmented Control" }</Link<Route>>
+                <h2>{ "Sidebar" }</h2>
+                <SidebarSettings />
+                <h2>{ "Error Boundary" }</h2>
+                <ErrorSettings />
             </div>
         </div>
     }
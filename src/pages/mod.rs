@@ -1,5 +1,6 @@
 pub mod dialog;
 pub mod home;
+pub mod not_found;
 pub mod pagination;
 pub mod segmented_control;
 pub mod tabs;
@@ -0,0 +1,106 @@
+use yew::prelude::*;
+use yew_router::prelude::*;
+
+use crate::{
+    components::{Breadcrumb, Breadcrumbs},
+    route::Route,
+};
+
+/// The routes which can be suggested for a path not found.
+const SUGGESTED_ROUTES: [Route; 4] = [
+    Route::Pagination,
+    Route::Dialog,
+    Route::Tabs,
+    Route::SegmentedControl,
+];
+
+/// Returns the number of single character edits turning `a` into `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a != *b);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+/// Returns the routes whose path looks like the first segment of `path`, the closest first.
+pub fn suggestions(path: &str) -> Vec<Route> {
+    let normalize = |path: &str| {
+        let segment = path.trim_matches('/').split('/').next().unwrap_or_default();
+        segment.to_lowercase().replace('-', "_")
+    };
+    let requested = normalize(path);
+    if requested.is_empty() {
+        return Vec::new();
+    }
+
+    let mut suggestions: Vec<(usize, Route)> = SUGGESTED_ROUTES
+        .iter()
+        .filter_map(|route| {
+            let candidate = normalize(&route.to_path());
+            let distance = if candidate.starts_with(&requested) || requested.starts_with(&candidate)
+            {
+                0
+            } else {
+                edit_distance(&requested, &candidate)
+            };
+
+            (distance <= candidate.len().div_ceil(3)).then(|| (distance, route.clone()))
+        })
+        .collect();
+    suggestions.sort_by_key(|(distance, _)| *distance);
+
+    suggestions.into_iter().map(|(_, route)| route).collect()
+}
+
+#[function_component(NotFound)]
+pub fn not_found() -> Html {
+    let path = use_location()
+        .map(|location| location.path().to_string())
+        .unwrap_or_default();
+    let suggestions = suggestions(&path);
+
+    html! {
+        <div style="display: flex; flex-direction: column; gap: 20px; padding: 20px">
+            <h1>{ "Page not found" }</h1>
+            <Breadcrumbs<Route> />
+            <p>{ format!("There is no page at {path}.") }</p>
+            if !suggestions.is_empty() {
+                <div>
+                    <p>{ "Did you mean:" }</p>
+                    <ul>
+                        { for suggestions.iter().map(|route| html! {
+                            <li><Link<Route> to={route.clone()}>{ route.label() }</Link<Route>></li>
+                        }) }
+                    </ul>
+                </div>
+            }
+            <Link<Route> to={Route::Home}>{ "Back to the home page" }</Link<Route>>
+        </div>
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn suggested_routes() {
+        assert_eq!(suggestions("/tab"), vec![Route::Tabs]);
+        assert_eq!(suggestions("/dialgo"), vec![Route::Dialog]);
+        assert_eq!(
+            suggestions("/Segmented-Control/extra"),
+            vec![Route::SegmentedControl]
+        );
+        assert_eq!(suggestions("/settings"), vec![]);
+        assert_eq!(suggestions("/"), vec![]);
+    }
+}
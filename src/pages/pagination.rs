@@ -1,7 +1,9 @@
 use yew::prelude::*;
-use yew_router::prelude::Link;
 
-use crate::{components::Pagination, route::Route};
+use crate::{
+    components::{Breadcrumbs, Pagination},
+    route::Route,
+};
 
 #[function_component(PaginationPage)]
 pub fn pagination() -> Html {
@@ -15,7 +17,7 @@ pub fn pagination() -> Html {
     html! {
         <div style="display: flex; flex-direction: column; gap: 20px; padding: 20px">
             <h1>{ "Pagination Showcase" }</h1>
-            <Link<Route> to={Route::Home}>{ "Home" }</Link<Route>>
+            <Breadcrumbs<Route> />
             <div style="display: flex; flex-direction: column; gap: 20px">
                 <Pagination total_pages=0 />
                 <Pagination total_pages=1 />
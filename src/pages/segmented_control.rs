@@ -1,17 +1,23 @@
 use yew::prelude::*;
-use yew_router::prelude::Link;
 
 use crate::{
-    components::{ControlItem, SegmentedControl},
+    components::{Breadcrumbs, ControlItem, SegmentedControl},
     route::Route,
 };
 
+#[derive(Clone, Debug, PartialEq)]
+enum Flower {
+    Dandelion,
+    Heather,
+    Lavender,
+}
+
 #[function_component(SegmentedControlPage)]
 pub fn segmented_control() -> Html {
     html! {
         <div style="display: flex; flex-direction: column; gap: 20px; padding: 20px">
             <h1>{ "Segmented Control Showcase" }</h1>
-            <Link<Route> to={Route::Home}>{ "Home" }</Link<Route>>
+            <Breadcrumbs<Route> />
             <div style="display: flex; flex-direction: column; gap: 20px">
                 <SegmentedControl
                     default_value="Heather"
@@ -21,6 +27,18 @@ pub fn segmented_control() -> Html {
                     <ControlItem value="Wayne" disabled=true>{ "Wayne" }</ControlItem>
                     <ControlItem value="Heather">{ "Heather" }</ControlItem>
                 </SegmentedControl>
+                <SegmentedControl<Flower>
+                    default_value={Flower::Lavender}
+                    on_change={Callback::from(|flower: Flower| log::info!("Segmented Control changed to: {flower:?}"))}
+                >
+                    <ControlItem<Flower> value={Flower::Dandelion}>
+                        { "Dandelion" }
+                    </ControlItem<Flower>>
+                    <ControlItem<Flower> value={Flower::Heather}>{ "Heather" }</ControlItem<Flower>>
+                    <ControlItem<Flower> value={Flower::Lavender}>
+                        { "Lavender" }
+                    </ControlItem<Flower>>
+                </SegmentedControl<Flower>>
             </div>
         </div>
     }
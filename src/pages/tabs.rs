@@ -1,8 +1,7 @@
 use yew::prelude::*;
-use yew_router::prelude::Link;
 
 use crate::{
-    components::{Tab, TabList, TabPanel, Tabs},
+    components::{Breadcrumbs, Tab, TabList, TabPanel, Tabs},
     route::Route,
 };
 
@@ -11,7 +10,7 @@ pub fn tabs() -> Html {
     html! {
         <div style="display: flex; flex-direction: column; gap: 20px; padding: 20px">
             <h1>{ "Tabs Showcase" }</h1>
-            <Link<Route> to={Route::Home}>{ "Home" }</Link<Route>>
+            <Breadcrumbs<Route> />
             <div style="display: flex; flex-direction: column; gap: 20px">
                 <Tabs
                     default_value="723"
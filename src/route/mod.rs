@@ -1,13 +1,17 @@
+use futures::future::LocalBoxFuture;
 use yew::prelude::*;
 use yew_router::prelude::*;
 
+use crate::components::{Breadcrumb, LazyPage, LazyRoute};
 use crate::pages::dialog::DialogPage;
 use crate::pages::home::Home;
+use crate::pages::not_found::NotFound;
 use crate::pages::pagination::PaginationPage;
 use crate::pages::segmented_control::SegmentedControlPage;
 use crate::pages::tabs::TabsPage;
+use crate::utils::load_stylesheet;
 
-#[derive(Clone, Routable, PartialEq)]
+#[derive(Clone, Debug, Routable, PartialEq)]
 pub enum Route {
     #[at("/")]
     Home,
@@ -24,13 +28,50 @@ pub enum Route {
     NotFound,
 }
 
-pub fn switch(routes: Route) -> Html {
-    match routes {
-        Route::Home => html! { <Home /> },
-        Route::Pagination => html! { <PaginationPage /> },
-        Route::Dialog => html! { <DialogPage /> },
-        Route::Tabs => html! { <TabsPage /> },
-        Route::SegmentedControl => html! { <SegmentedControlPage /> },
-        Route::NotFound => html! { <h1>{ "404" }</h1> },
+impl Breadcrumb for Route {
+    fn parent(&self) -> Option<Self> {
+        match self {
+            Self::Home => None,
+            _ => Some(Self::Home),
+        }
     }
+
+    fn label(&self) -> String {
+        match self {
+            Self::Home => "Home",
+            Self::Pagination => "Pagination",
+            Self::Dialog => "Dialog",
+            Self::Tabs => "Tabs",
+            Self::SegmentedControl => "Segmented Control",
+            Self::NotFound => "Not Found",
+        }
+        .to_string()
+    }
+}
+
+impl LazyRoute for Route {
+    // Trunk builds a single wasm module per binary, so the pages stay in the main bundle: only the
+    // styles of the pages are split out of it.
+    fn load(&self) -> Option<LocalBoxFuture<'static, Result<(), String>>> {
+        match self {
+            Self::Pagination => load_stylesheet("styles/pagination.css"),
+            Self::SegmentedControl => load_stylesheet("styles/segmented_control.css"),
+            _ => None,
+        }
+    }
+
+    fn page(&self) -> Html {
+        match self {
+            Self::Home => html! { <Home /> },
+            Self::Pagination => html! { <PaginationPage /> },
+            Self::Dialog => html! { <DialogPage /> },
+            Self::Tabs => html! { <TabsPage /> },
+            Self::SegmentedControl => html! { <SegmentedControlPage /> },
+            Self::NotFound => html! { <NotFound /> },
+        }
+    }
+}
+
+pub fn switch(route: Route) -> Html {
+    html! { <LazyPage<Route> {route} /> }
 }
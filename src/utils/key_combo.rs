@@ -0,0 +1,199 @@
+use std::fmt;
+use std::str::FromStr;
+
+use yew::prelude::*;
+
+/// A keyboard shortcut, e.g. `"mod+b"` or `"ctrl+shift+k"`.
+///
+/// `mod` stands for Ctrl, or Cmd on macOS: either of them is accepted. The key can be `+` itself,
+/// e.g. `"mod++"`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct KeyCombo {
+    /// The [`KeyboardEvent::key`], lowercase.
+    pub key: String,
+    pub mod_key: bool,
+    pub ctrl: bool,
+    pub meta: bool,
+    pub alt: bool,
+    pub shift: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseKeyComboError {
+    MissingKey,
+    UnknownModifier(String),
+}
+
+impl fmt::Display for ParseKeyComboError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingKey => write!(f, "the key combo has no key"),
+            Self::UnknownModifier(modifier) => write!(f, "unknown modifier \"{modifier}\""),
+        }
+    }
+}
+
+impl FromStr for KeyCombo {
+    type Err = ParseKeyComboError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (modifiers, key) = match s.rsplit_once('+') {
+            // The key itself is "+", e.g. "mod++" or "+".
+            Some((rest, "")) if rest.trim().is_empty() || rest.trim_end().ends_with('+') => {
+                (rest.trim_end().strip_suffix('+').unwrap_or_default(), "+")
+            }
+            Some((modifiers, key)) => (modifiers, key),
+            None => ("", s),
+        };
+        let key = Some(key.trim().to_lowercase())
+            .filter(|key| !key.is_empty())
+            .ok_or(ParseKeyComboError::MissingKey)?;
+        let parts: Vec<_> = if modifiers.trim().is_empty() {
+            Vec::new()
+        } else {
+            modifiers
+                .split('+')
+                .map(|part| part.trim().to_lowercase())
+                .collect()
+        };
+
+        let mut combo = Self {
+            key,
+            ..Self::default()
+        };
+        for modifier in parts {
+            match modifier.as_str() {
+                "mod" => combo.mod_key = true,
+                "ctrl" | "control" => combo.ctrl = true,
+                "meta" | "cmd" => combo.meta = true,
+                "alt" | "option" => combo.alt = true,
+                "shift" => combo.shift = true,
+                _ => return Err(ParseKeyComboError::UnknownModifier(modifier)),
+            }
+        }
+
+        Ok(combo)
+    }
+}
+
+impl KeyCombo {
+    /// Whether the pressed key and modifiers are exactly the ones of the combo.
+    pub fn matches_keys(&self, key: &str, ctrl: bool, meta: bool, alt: bool, shift: bool) -> bool {
+        let mod_pressed = ctrl || meta;
+        let (ctrl_expected, meta_expected) = if self.mod_key {
+            // Either of them, unless the other one is required as well.
+            (
+                self.ctrl || (ctrl && !self.meta),
+                self.meta || (meta && !self.ctrl),
+            )
+        } else {
+            (self.ctrl, self.meta)
+        };
+
+        // Typing a symbol may take Shift, e.g. "+" on a US layout, so it is only checked if required.
+        let mut chars = self.key.chars();
+        let is_symbol =
+            matches!((chars.next(), chars.next()), (Some(c), None) if c.is_ascii_punctuation());
+        let shift_matches = shift == self.shift || (is_symbol && !self.shift);
+
+        key.to_lowercase() == self.key
+            && (!self.mod_key || mod_pressed)
+            && ctrl == ctrl_expected
+            && meta == meta_expected
+            && alt == self.alt
+            && shift_matches
+    }
+
+    pub fn matches(&self, e: &KeyboardEvent) -> bool {
+        self.matches_keys(
+            &e.key(),
+            e.ctrl_key(),
+            e.meta_key(),
+            e.alt_key(),
+            e.shift_key(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            "mod+b".parse(),
+            Ok(KeyCombo {
+                key: "b".to_string(),
+                mod_key: true,
+                ..KeyCombo::default()
+            })
+        );
+        assert_eq!(
+            "Ctrl + Shift + K".parse(),
+            Ok(KeyCombo {
+                key: "k".to_string(),
+                ctrl: true,
+                shift: true,
+                ..KeyCombo::default()
+            })
+        );
+        assert_eq!("".parse::<KeyCombo>(), Err(ParseKeyComboError::MissingKey));
+        assert_eq!(
+            "mod+".parse::<KeyCombo>(),
+            Err(ParseKeyComboError::MissingKey)
+        );
+        assert_eq!(
+            "mod++".parse(),
+            Ok(KeyCombo {
+                key: "+".to_string(),
+                mod_key: true,
+                ..KeyCombo::default()
+            })
+        );
+        assert_eq!(
+            "ctrl + +".parse(),
+            Ok(KeyCombo {
+                key: "+".to_string(),
+                ctrl: true,
+                ..KeyCombo::default()
+            })
+        );
+        assert_eq!(
+            "+".parse(),
+            Ok(KeyCombo {
+                key: "+".to_string(),
+                ..KeyCombo::default()
+            })
+        );
+        assert_eq!(
+            "hyper+b".parse::<KeyCombo>(),
+            Err(ParseKeyComboError::UnknownModifier("hyper".to_string()))
+        );
+    }
+
+    #[test]
+    fn matches_keys() {
+        let combo: KeyCombo = "mod+b".parse().unwrap();
+
+        assert!(combo.matches_keys("b", true, false, false, false));
+        assert!(combo.matches_keys("B", false, true, false, false));
+        assert!(!combo.matches_keys("b", false, false, false, false));
+        assert!(!combo.matches_keys("b", true, false, false, true));
+        assert!(!combo.matches_keys("n", true, false, false, false));
+
+        let combo: KeyCombo = "ctrl+alt+s".parse().unwrap();
+
+        assert!(combo.matches_keys("s", true, false, true, false));
+        assert!(!combo.matches_keys("s", false, true, true, false));
+        assert!(!combo.matches_keys("s", true, true, true, false));
+
+        let combo: KeyCombo = "mod++".parse().unwrap();
+
+        assert!(combo.matches_keys("+", true, false, false, false));
+        assert!(
+            combo.matches_keys("+", true, false, false, true),
+            "Shift may be needed to type the symbol"
+        );
+    }
+}
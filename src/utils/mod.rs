@@ -0,0 +1,5 @@
+pub mod key_combo;
+pub mod stylesheet;
+
+pub use key_combo::*;
+pub use stylesheet::*;
@@ -0,0 +1,109 @@
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use futures::channel::oneshot;
+use futures::future::{FutureExt, LocalBoxFuture, Shared};
+use gloo::events::EventListener;
+
+type Loading = Shared<LocalBoxFuture<'static, Result<(), String>>>;
+
+thread_local! {
+    /// The stylesheets added by [`load_stylesheet`], `None` once loaded.
+    static STYLESHEETS: RefCell<HashMap<String, Option<Loading>>> = RefCell::new(HashMap::new());
+}
+
+/// Returns `path` relative to the directory of the `base` URL.
+fn resolve(base: &str, path: &str) -> String {
+    let directory = base.rfind('/').map_or("", |end| &base[..=end]);
+
+    format!("{directory}{}", path.trim_start_matches('/'))
+}
+
+/// Adds a `<link rel="stylesheet">` to the head and waits for it to load. It is removed again if
+/// it fails, so that it can be retried.
+async fn add_stylesheet(href: String) -> Result<(), String> {
+    let error = || format!("Failed to load the stylesheet {href}");
+    let link = gloo::utils::document()
+        .create_element("link")
+        .map_err(|_| error())?;
+    link.set_attribute("rel", "stylesheet")
+        .map_err(|_| error())?;
+    link.set_attribute("href", &href).map_err(|_| error())?;
+
+    let (sender, receiver) = oneshot::channel();
+    let sender = Rc::new(Cell::new(Some(sender)));
+    let settle = |loaded: bool| {
+        let sender = sender.clone();
+        move |_: &web_sys::Event| {
+            if let Some(sender) = sender.take() {
+                let _ = sender.send(loaded);
+            }
+        }
+    };
+    let _load_listener = EventListener::once(&link, "load", settle(true));
+    let _error_listener = EventListener::once(&link, "error", settle(false));
+    gloo::utils::head()
+        .append_child(&link)
+        .map_err(|_| error())?;
+
+    if receiver.await != Ok(true) {
+        link.remove();
+        return Err(error());
+    }
+
+    Ok(())
+}
+
+/// Loads the stylesheet at `path` once, e.g. the styles of a page loaded with it.
+///
+/// `path` is relative to the base URL of the document, the `public_url` Trunk builds with.
+/// Returns `None` once it is loaded, and otherwise the loading shared by all of its callers.
+pub fn load_stylesheet(path: &str) -> Option<LocalBoxFuture<'static, Result<(), String>>> {
+    let base = gloo::utils::document()
+        .base_uri()
+        .ok()
+        .flatten()
+        .unwrap_or_default();
+    let href = resolve(&base, path);
+    let loading = STYLESHEETS.with_borrow_mut(|stylesheets| {
+        stylesheets
+            .entry(href.clone())
+            .or_insert_with(|| Some(add_stylesheet(href.clone()).boxed_local().shared()))
+            .clone()
+    })?;
+
+    Some(
+        async move {
+            let result = loading.await;
+            STYLESHEETS.with_borrow_mut(|stylesheets| match result {
+                Ok(()) => stylesheets.insert(href, None),
+                Err(_) => stylesheets.remove(&href),
+            });
+
+            result
+        }
+        .boxed_local(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn path_from_base() {
+        assert_eq!(
+            resolve("https://example.com/", "styles/tabs.css"),
+            "https://example.com/styles/tabs.css"
+        );
+        assert_eq!(
+            resolve("https://example.com/app/", "/styles/tabs.css"),
+            "https://example.com/app/styles/tabs.css"
+        );
+        assert_eq!(
+            resolve("https://example.com/app/index.html", "styles/tabs.css"),
+            "https://example.com/app/styles/tabs.css"
+        );
+    }
+}